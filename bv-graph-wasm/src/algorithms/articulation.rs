@@ -8,6 +8,7 @@
 //! disconnect groups of related work.
 
 use crate::graph::DiGraph;
+use serde::Serialize;
 use std::collections::HashSet;
 
 /// Find articulation points (cut vertices) using Tarjan's algorithm.
@@ -68,6 +69,7 @@ pub fn articulation_points(graph: &DiGraph) -> Vec<usize> {
 }
 
 /// DFS for Tarjan's articulation point algorithm.
+#[allow(clippy::too_many_arguments)]
 fn tarjan_dfs(
     v: usize,
     neighbors: &[Vec<usize>],
@@ -167,6 +169,7 @@ pub fn bridges(graph: &DiGraph) -> Vec<(usize, usize)> {
 }
 
 /// DFS for bridge detection.
+#[allow(clippy::too_many_arguments)]
 fn bridge_dfs(
     v: usize,
     neighbors: &[Vec<usize>],
@@ -199,6 +202,48 @@ fn bridge_dfs(
     }
 }
 
+/// Cut-point and bridge markers for badges and edge styling.
+#[derive(Debug, Clone, Serialize)]
+pub struct StructuralBadges {
+    /// Whether each node is an articulation point, parallel to node indices
+    pub cut_point: Vec<bool>,
+    /// Bridge edges in dependency direction (blocker, blocked)
+    pub bridges: Vec<(usize, usize)>,
+    /// Number of bridges touching each node, parallel to node indices
+    pub bridge_degree: Vec<usize>,
+}
+
+/// Collect articulation points and bridges in one pass for display.
+///
+/// `bridges` returns canonical (min, max) pairs from the undirected view;
+/// here they are turned back into the direction of an existing edge so
+/// the view can match them against the edges it draws.
+pub fn structural_badges(graph: &DiGraph) -> StructuralBadges {
+    let n = graph.len();
+    let mut cut_point = vec![false; n];
+    for v in articulation_points(graph) {
+        cut_point[v] = true;
+    }
+    let mut bridge_degree = vec![0usize; n];
+    let bridges = bridges(graph)
+        .into_iter()
+        .map(|(a, b)| {
+            bridge_degree[a] += 1;
+            bridge_degree[b] += 1;
+            if graph.successors_slice(a).contains(&b) {
+                (a, b)
+            } else {
+                (b, a)
+            }
+        })
+        .collect();
+    StructuralBadges {
+        cut_point,
+        bridges,
+        bridge_degree,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -387,4 +432,24 @@ mod tests {
         let ap = articulation_points(&graph);
         assert!(ap.is_empty());
     }
+
+    #[test]
+    fn test_structural_badges() {
+        // c -> a -> b, b -> d (a and b are cut points, all edges are bridges)
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(c, a);
+        graph.add_edge(a, b);
+        graph.add_edge(b, d);
+
+        let badges = structural_badges(&graph);
+        assert_eq!(badges.cut_point, vec![true, true, false, false]);
+        let mut edges = badges.bridges.clone();
+        edges.sort_unstable();
+        assert_eq!(edges, vec![(a, b), (b, d), (c, a)]);
+        assert_eq!(badges.bridge_degree, vec![2, 2, 1, 1]);
+    }
 }
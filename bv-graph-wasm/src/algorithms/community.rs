@@ -0,0 +1,172 @@
+//! Community detection by label propagation.
+//!
+//! Groups issues that are more densely connected to each other than to
+//! the rest of the graph, using the undirected view. Every node starts
+//! with its own label and repeatedly adopts the most common label among
+//! its neighbors. Nodes are visited from lowest to highest degree (then by
+//! index), so peripheral issues settle before the hubs that join groups,
+//! and ties go to the smallest label; the result is deterministic for a
+//! given graph.
+//!
+//! Clusters are keyed by their smallest member ID rather than by index,
+//! so user-chosen cluster names can be stored per project and reapplied
+//! after the graph changes, as long as the anchoring issue stays put.
+
+use crate::graph::DiGraph;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Upper bound on propagation rounds (it usually settles in a handful).
+const MAX_ROUNDS: usize = 20;
+
+/// A detected cluster.
+#[derive(Debug, Clone, Serialize)]
+pub struct Cluster {
+    /// Stable key: the smallest member ID
+    pub key: String,
+    /// User-chosen name, or the key when none is set
+    pub name: String,
+    /// Member node indices (sorted)
+    pub nodes: Vec<usize>,
+}
+
+/// Result of community detection.
+#[derive(Debug, Clone, Serialize)]
+pub struct Communities {
+    /// Index into `clusters` per node
+    pub community: Vec<usize>,
+    /// Clusters, largest first (ties by key)
+    pub clusters: Vec<Cluster>,
+}
+
+/// Assign a community label to every node by label propagation.
+///
+/// Returns the raw label per node; labels are node indices of some member.
+pub fn label_propagation(graph: &DiGraph) -> Vec<usize> {
+    let n = graph.len();
+    let mut label: Vec<usize> = (0..n).collect();
+    let mut counts: HashMap<usize, usize> = HashMap::new();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by_key(|&v| (graph.in_degree(v) + graph.out_degree(v), v));
+
+    for _ in 0..MAX_ROUNDS {
+        let mut changed = false;
+        for &v in &order {
+            counts.clear();
+            let neighbors = graph
+                .successors_slice(v)
+                .iter()
+                .chain(graph.predecessors_slice(v))
+                .filter(|&&w| w != v);
+            for &w in neighbors {
+                *counts.entry(label[w]).or_insert(0) += 1;
+            }
+            // Most frequent label, smallest on ties; keeping the current
+            // label when it is among the best avoids needless flip-flopping
+            let Some(best) = counts.values().copied().max() else {
+                continue;
+            };
+            if counts.get(&label[v]) == Some(&best) {
+                continue;
+            }
+            let next = counts
+                .iter()
+                .filter(|&(_, &c)| c == best)
+                .map(|(&l, _)| l)
+                .min()
+                .unwrap_or(label[v]);
+            label[v] = next;
+            changed = true;
+        }
+        if !changed {
+            break;
+        }
+    }
+    label
+}
+
+/// Detect communities and apply stored cluster names.
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `names` - Stored names by cluster key; unknown keys are ignored
+pub fn communities(graph: &DiGraph, names: &HashMap<String, String>) -> Communities {
+    let n = graph.len();
+    let label = label_propagation(graph);
+
+    let mut members: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (v, &l) in label.iter().enumerate() {
+        members.entry(l).or_default().push(v);
+    }
+    let mut clusters: Vec<Cluster> = members
+        .into_values()
+        .map(|nodes| {
+            let key = nodes
+                .iter()
+                .filter_map(|&v| graph.node_id(v))
+                .min()
+                .unwrap_or_default();
+            let name = names.get(&key).cloned().unwrap_or_else(|| key.clone());
+            Cluster { key, name, nodes }
+        })
+        .collect();
+    clusters.sort_by(|a, b| {
+        b.nodes
+            .len()
+            .cmp(&a.nodes.len())
+            .then_with(|| a.key.cmp(&b.key))
+    });
+
+    let mut community = vec![0usize; n];
+    for (i, cluster) in clusters.iter().enumerate() {
+        for &v in &cluster.nodes {
+            community[v] = i;
+        }
+    }
+    Communities {
+        community,
+        clusters,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two triangles joined by a single edge c -> d, plus an isolated node g.
+    fn two_triangles() -> DiGraph {
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e", "f", "g"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)] {
+            graph.add_edge(u, v);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_communities_split_triangles() {
+        let graph = two_triangles();
+        let result = communities(&graph, &HashMap::new());
+        let keys: Vec<&str> = result.clusters.iter().map(|c| c.key.as_str()).collect();
+        assert_eq!(keys, vec!["a", "d", "g"]);
+        assert_eq!(result.clusters[0].nodes, vec![0, 1, 2]);
+        assert_eq!(result.clusters[1].nodes, vec![3, 4, 5]);
+        assert_eq!(result.community, vec![0, 0, 0, 1, 1, 1, 2]);
+    }
+
+    #[test]
+    fn test_communities_names_and_determinism() {
+        let graph = two_triangles();
+        let names = HashMap::from([
+            ("d".to_string(), "Backend".to_string()),
+            ("zzz".to_string(), "Stale".to_string()),
+        ]);
+        let result = communities(&graph, &names);
+        assert_eq!(result.clusters[1].name, "Backend");
+        assert_eq!(result.clusters[0].name, "a");
+        assert_eq!(label_propagation(&graph), label_propagation(&graph));
+        assert!(communities(&DiGraph::new(), &names).clusters.is_empty());
+    }
+}
@@ -3,6 +3,8 @@
 //! Computes the longest dependency chain from roots to each node.
 //! Nodes with high heights are deep in the dependency tree.
 
+use crate::algorithms::slack::zero_slack_nodes;
+use crate::algorithms::subgraph::extract_subgraph;
 use crate::algorithms::topo::topological_sort;
 use crate::graph::DiGraph;
 
@@ -68,6 +70,78 @@ pub fn critical_path_length(graph: &DiGraph) -> f64 {
         .fold(0.0, f64::max)
 }
 
+/// Get the length of the longest chain of remaining (open) work.
+///
+/// Closed nodes are removed before the analysis. Returns 0 if the open
+/// subgraph is cyclic.
+pub fn open_critical_path_length(graph: &DiGraph, closed_set: &[bool]) -> f64 {
+    let open: Vec<usize> = (0..graph.len())
+        .filter(|&v| !closed_set.get(v).copied().unwrap_or(false))
+        .collect();
+    critical_path_length(&extract_subgraph(graph, &open))
+}
+
+/// Get every open node lying on a longest chain of remaining work.
+///
+/// Closed nodes are removed before the analysis, so the result shrinks
+/// (or shifts) as issues close. Unlike `critical_path_nodes`, which only
+/// returns the chain ends, this returns all members of the critical chains
+/// (zero slack in the open subgraph), suitable for a per-row "CP" badge.
+///
+/// # Returns
+/// Sorted node indices in the original graph. Empty for cyclic open subgraphs.
+pub fn critical_path_members(graph: &DiGraph, closed_set: &[bool]) -> Vec<usize> {
+    let open: Vec<usize> = (0..graph.len())
+        .filter(|&v| !closed_set.get(v).copied().unwrap_or(false))
+        .collect();
+    if open.is_empty() {
+        return Vec::new();
+    }
+
+    // extract_subgraph renumbers nodes in input order, so sub index i == open[i]
+    let sub = extract_subgraph(graph, &open);
+    if topological_sort(&sub).is_none() {
+        return Vec::new();
+    }
+
+    zero_slack_nodes(&sub)
+        .into_iter()
+        .map(|i| open[i])
+        .collect()
+}
+
+/// Get the edges along the critical chains of remaining (open) work.
+///
+/// An edge is critical when both endpoints have zero slack in the open
+/// subgraph and it advances the height by exactly one, i.e. it lies on a
+/// longest chain. Shortcut edges between critical nodes are excluded.
+///
+/// # Returns
+/// Sorted (from, to) pairs in the original graph. Empty for cyclic open subgraphs.
+pub fn critical_path_edges(graph: &DiGraph, closed_set: &[bool]) -> Vec<(usize, usize)> {
+    let open: Vec<usize> = (0..graph.len())
+        .filter(|&v| !closed_set.get(v).copied().unwrap_or(false))
+        .collect();
+    let sub = extract_subgraph(graph, &open);
+    if open.is_empty() || topological_sort(&sub).is_none() {
+        return Vec::new();
+    }
+
+    let heights = critical_path_heights(&sub);
+    let mut on_path = vec![false; sub.len()];
+    for v in zero_slack_nodes(&sub) {
+        on_path[v] = true;
+    }
+
+    let mut edges: Vec<(usize, usize)> = sub
+        .edges()
+        .filter(|&(u, v)| on_path[u] && on_path[v] && (heights[v] - heights[u] - 1.0).abs() < 0.001)
+        .map(|(u, v)| (open[u], open[v]))
+        .collect();
+    edges.sort_unstable();
+    edges
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -223,4 +297,83 @@ mod tests {
         assert_eq!(heights[d], 2.0);
         assert_eq!(heights[e], 2.0);
     }
+
+    #[test]
+    fn test_open_critical_path_length() {
+        // a -> b -> c, closing a shortens the remaining chain
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+
+        assert_eq!(open_critical_path_length(&g, &[]), 3.0);
+        assert_eq!(open_critical_path_length(&g, &[true, false, false]), 2.0);
+        assert_eq!(open_critical_path_length(&g, &[true, true, true]), 0.0);
+    }
+
+    #[test]
+    fn test_critical_path_members_all_open() {
+        // a -> b -> c, d -> c, e isolated
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_node("e");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.add_edge(d, c);
+
+        assert_eq!(critical_path_members(&g, &[]), vec![a, b, c]);
+    }
+
+    #[test]
+    fn test_critical_path_members_recomputed_on_close() {
+        // a -> b -> c and d -> e -> f -> g: closing d and e makes a->b->c critical
+        let mut g = DiGraph::new();
+        let ids: Vec<usize> = ["a", "b", "c", "d", "e", "f", "g"]
+            .iter()
+            .map(|id| g.add_node(id))
+            .collect();
+        g.add_edge(ids[0], ids[1]);
+        g.add_edge(ids[1], ids[2]);
+        g.add_edge(ids[3], ids[4]);
+        g.add_edge(ids[4], ids[5]);
+        g.add_edge(ids[5], ids[6]);
+
+        assert_eq!(critical_path_members(&g, &[]), vec![3, 4, 5, 6]);
+
+        let closed = vec![false, false, false, true, true, false, false];
+        assert_eq!(critical_path_members(&g, &closed), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_critical_path_members_all_closed() {
+        let mut g = DiGraph::new();
+        g.add_node("a");
+        assert!(critical_path_members(&g, &[true]).is_empty());
+    }
+
+    #[test]
+    fn test_critical_path_edges_skip_shortcuts() {
+        // a -> b -> c with shortcut a -> c; d -> c is off the chain
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.add_edge(a, c);
+        g.add_edge(d, c);
+
+        assert_eq!(critical_path_edges(&g, &[]), vec![(a, b), (b, c)]);
+        // Closing a leaves every open edge into c as a chain of length 2
+        assert_eq!(
+            critical_path_edges(&g, &[true, false, false, false]),
+            vec![(b, c), (d, c)]
+        );
+    }
 }
@@ -40,6 +40,7 @@ pub fn tarjan_scc(graph: &DiGraph) -> SCCResult {
     let mut stack: Vec<usize> = Vec::new();
     let mut components: Vec<Vec<usize>> = Vec::new();
 
+    #[allow(clippy::too_many_arguments)]
     fn strongconnect(
         v: usize,
         graph: &DiGraph,
@@ -111,6 +112,14 @@ pub fn has_cycles(graph: &DiGraph) -> bool {
     tarjan_scc(graph).has_cycles
 }
 
+/// Whether an SCC holds a cycle: more than one member, or a self-loop.
+pub fn is_cycle_component(graph: &DiGraph, component: &[usize]) -> bool {
+    component.len() > 1
+        || component
+            .first()
+            .is_some_and(|&v| graph.successors_slice(v).contains(&v))
+}
+
 /// Enumerate elementary cycles using Johnson's algorithm.
 ///
 /// Reference: Donald B. Johnson, "Finding All the Elementary Circuits of a Directed Graph"
@@ -145,6 +154,7 @@ pub fn enumerate_cycles(graph: &DiGraph, max_cycles: usize) -> Vec<Vec<usize>> {
     }
 
     // Circuit search from start vertex
+    #[allow(clippy::too_many_arguments)]
     fn circuit(
         v: usize,
         start: usize,
@@ -178,8 +188,8 @@ pub fn enumerate_cycles(graph: &DiGraph, max_cycles: usize) -> Vec<Vec<usize>> {
                     stack.pop();
                     return found;
                 }
-            } else if !blocked[w] {
-                if circuit(
+            } else if !blocked[w]
+                && circuit(
                     w,
                     start,
                     graph,
@@ -189,9 +199,9 @@ pub fn enumerate_cycles(graph: &DiGraph, max_cycles: usize) -> Vec<Vec<usize>> {
                     cycles,
                     max_cycles,
                     min_node,
-                ) {
-                    found = true;
-                }
+                )
+            {
+                found = true;
             }
         }
 
@@ -216,9 +226,7 @@ pub fn enumerate_cycles(graph: &DiGraph, max_cycles: usize) -> Vec<Vec<usize>> {
         }
 
         // Reset blocked state
-        for b in &mut blocked {
-            *b = false;
-        }
+        blocked.fill(false);
         for s in &mut blocked_map {
             s.clear();
         }
@@ -312,7 +320,7 @@ pub fn cycle_break_suggestions(
     max_cycles_to_enumerate: usize,
 ) -> CycleBreakResult {
     let scc = tarjan_scc(graph);
-    if !scc.has_cycles {
+    if !scc.components.iter().any(|c| is_cycle_component(graph, c)) {
         return CycleBreakResult {
             suggestions: Vec::new(),
             total_cycles: 0,
@@ -329,10 +337,7 @@ pub fn cycle_break_suggestions(
         std::collections::HashMap::new();
 
     for cycle in cycles {
-        if cycle.len() < 2 {
-            continue;
-        }
-        // Count edges in this cycle
+        // Count edges in this cycle (a self-loop is the edge v -> v)
         for i in 0..cycle.len() {
             let from = cycle[i];
             let to = cycle[(i + 1) % cycle.len()];
@@ -340,11 +345,11 @@ pub fn cycle_break_suggestions(
         }
     }
 
-    // Build set of nodes in non-trivial SCCs
+    // Build set of nodes in cyclic SCCs (including self-loops)
     let cycle_nodes: HashSet<usize> = scc
         .components
         .iter()
-        .filter(|c| c.len() > 1)
+        .filter(|c| is_cycle_component(graph, c))
         .flat_map(|c| c.iter().copied())
         .collect();
 
@@ -430,6 +435,158 @@ pub fn quick_cycle_break_edges(graph: &DiGraph, limit: usize) -> Vec<CycleBreakI
     suggestions
 }
 
+// ============================================================================
+// Cycle Inspection (per-SCC breakdown)
+// ============================================================================
+
+/// A cycle-containing strongly connected component.
+#[derive(Debug, Clone, Serialize)]
+pub struct CycleComponent {
+    /// Member node indices (sorted)
+    pub nodes: Vec<usize>,
+    /// Member node IDs for display, parallel to `nodes`
+    pub ids: Vec<Option<String>>,
+    /// Dependency edges inside the component
+    pub edges: Vec<(usize, usize)>,
+    /// Suggested edges to cut, best first
+    pub suggestions: Vec<CycleBreakItem>,
+}
+
+/// Result of cycle inspection.
+#[derive(Debug, Clone, Serialize)]
+pub struct CycleInspection {
+    /// SCCs that hold a cycle (self-loops included), largest first
+    pub components: Vec<CycleComponent>,
+    /// Total cycles enumerated across all components
+    pub total_cycles: usize,
+    /// Whether cycle enumeration was truncated
+    pub truncated: bool,
+}
+
+/// Break down cycles per SCC with the edges to cut inside each one.
+///
+/// Suggestions come from `cycle_break_suggestions` and keep its ordering
+/// (cycles_broken desc, then collateral asc) within each component. Edges
+/// that connect two different SCCs are never part of a cycle and are dropped.
+/// Self-loops count as cycles, as in `cycle_overlay`, and suggest their own
+/// edge.
+///
+/// # Arguments
+/// * `graph` - The directed graph
+/// * `suggestions_per_component` - Maximum suggestions to keep per SCC
+/// * `max_cycles_to_enumerate` - Max cycles to enumerate for scoring
+pub fn cycle_inspection(
+    graph: &DiGraph,
+    suggestions_per_component: usize,
+    max_cycles_to_enumerate: usize,
+) -> CycleInspection {
+    let scc = tarjan_scc(graph);
+    let breaks = cycle_break_suggestions(graph, usize::MAX, max_cycles_to_enumerate);
+
+    let mut components: Vec<CycleComponent> = scc
+        .components
+        .into_iter()
+        .filter(|c| is_cycle_component(graph, c))
+        .map(|mut nodes| {
+            nodes.sort_unstable();
+            let members: HashSet<usize> = nodes.iter().copied().collect();
+
+            let edges: Vec<(usize, usize)> = nodes
+                .iter()
+                .flat_map(|&from| {
+                    graph
+                        .successors_slice(from)
+                        .iter()
+                        .filter(|to| members.contains(to))
+                        .map(move |&to| (from, to))
+                })
+                .collect();
+
+            let suggestions: Vec<CycleBreakItem> = breaks
+                .suggestions
+                .iter()
+                .filter(|s| members.contains(&s.from) && members.contains(&s.to))
+                .take(suggestions_per_component)
+                .cloned()
+                .collect();
+
+            CycleComponent {
+                ids: nodes.iter().map(|&v| graph.node_id(v)).collect(),
+                nodes,
+                edges,
+                suggestions,
+            }
+        })
+        .collect();
+
+    // Largest first; ties by lowest member index
+    components.sort_by(|a, b| {
+        b.nodes
+            .len()
+            .cmp(&a.nodes.len())
+            .then(a.nodes.cmp(&b.nodes))
+    });
+
+    CycleInspection {
+        components,
+        total_cycles: breaks.total_cycles,
+        truncated: breaks.truncated,
+    }
+}
+
+// ============================================================================
+// Cycle Overlay (highlighting across views)
+// ============================================================================
+
+/// Per-node and per-edge cycle membership for highlighting.
+#[derive(Debug, Clone, Serialize)]
+pub struct CycleOverlay {
+    /// Index into `cycles` for nodes on a cycle, parallel to node indices
+    pub node_cycle: Vec<Option<usize>>,
+    /// Edges that lie on a cycle (both ends in the same cycle group)
+    pub edges: Vec<(usize, usize)>,
+    /// Cycle groups in jump order, each with sorted member indices
+    pub cycles: Vec<Vec<usize>>,
+}
+
+/// Mark every node and edge that lies on a dependency cycle.
+///
+/// Cycles are grouped by strongly connected component, so overlapping
+/// cycles share one group and the overlay stays linear in the graph size.
+/// Self-loops form a group of one. Groups are ordered by their lowest
+/// member index, which gives jump-to-next a stable order that follows
+/// the list view.
+pub fn cycle_overlay(graph: &DiGraph) -> CycleOverlay {
+    let n = graph.len();
+    let mut cycles: Vec<Vec<usize>> = tarjan_scc(graph)
+        .components
+        .into_iter()
+        .filter(|c| is_cycle_component(graph, c))
+        .map(|mut c| {
+            c.sort_unstable();
+            c
+        })
+        .collect();
+    cycles.sort_unstable_by_key(|c| c[0]);
+
+    let mut node_cycle = vec![None; n];
+    for (i, members) in cycles.iter().enumerate() {
+        for &v in members {
+            node_cycle[v] = Some(i);
+        }
+    }
+    let edges = graph
+        .edges()
+        .filter(|&(u, v)| node_cycle[u].is_some() && node_cycle[u] == node_cycle[v])
+        .collect();
+
+    CycleOverlay {
+        node_cycle,
+        edges,
+        cycles,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -875,4 +1032,120 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_cycle_inspection_acyclic() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+
+        let result = cycle_inspection(&graph, 3, 100);
+        assert!(result.components.is_empty());
+        assert_eq!(result.total_cycles, 0);
+    }
+
+    #[test]
+    fn test_cycle_inspection_per_component() {
+        // a <-> b (2-cycle), c -> d -> e -> c (3-cycle), b -> c bridge
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        let e = graph.add_node("e");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+        graph.add_edge(b, c);
+        graph.add_edge(c, d);
+        graph.add_edge(d, e);
+        graph.add_edge(e, c);
+
+        let result = cycle_inspection(&graph, 10, 100);
+        assert_eq!(result.components.len(), 2);
+        assert_eq!(result.total_cycles, 2);
+
+        // Largest component first
+        let big = &result.components[0];
+        assert_eq!(big.nodes, vec![c, d, e]);
+        assert_eq!(big.ids[0], Some("c".to_string()));
+        assert_eq!(big.edges.len(), 3);
+        assert_eq!(big.suggestions.len(), 3);
+        assert!(big.suggestions.iter().all(|s| big.nodes.contains(&s.to)));
+
+        // The b -> c edge crosses components and is never suggested
+        let small = &result.components[1];
+        assert_eq!(small.nodes, vec![a, b]);
+        assert_eq!(small.edges.len(), 2);
+        assert!(small.suggestions.iter().all(|s| s.to != c));
+    }
+
+    #[test]
+    fn test_cycle_inspection_suggestion_limit() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, a);
+
+        let result = cycle_inspection(&graph, 1, 100);
+        assert_eq!(result.components[0].suggestions.len(), 1);
+    }
+
+    #[test]
+    fn test_cycle_inspection_self_loop() {
+        // a -> a, a -> b: the overlay shows {a}, so the inspector lists it
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, a);
+        graph.add_edge(a, b);
+
+        let result = cycle_inspection(&graph, 3, 100);
+        assert_eq!(result.components.len(), 1);
+        assert_eq!(result.total_cycles, 1);
+        let loop_component = &result.components[0];
+        assert_eq!(loop_component.nodes, vec![a]);
+        assert_eq!(loop_component.edges, vec![(a, a)]);
+        assert_eq!(loop_component.suggestions.len(), 1);
+        let suggestion = &loop_component.suggestions[0];
+        assert_eq!((suggestion.from, suggestion.to), (a, a));
+        assert_eq!(suggestion.cycles_broken, 1);
+        assert_eq!(cycle_overlay(&graph).cycles, vec![vec![a]]);
+    }
+
+    #[test]
+    fn test_cycle_overlay() {
+        // d <-> b, b -> c, e -> e, a -> b
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        let e = graph.add_node("e");
+        graph.add_edge(d, b);
+        graph.add_edge(b, d);
+        graph.add_edge(b, c);
+        graph.add_edge(e, e);
+        graph.add_edge(a, b);
+
+        let overlay = cycle_overlay(&graph);
+        assert_eq!(overlay.cycles, vec![vec![b, d], vec![e]]);
+        assert_eq!(
+            overlay.node_cycle,
+            vec![None, Some(0), None, Some(0), Some(1)]
+        );
+        assert_eq!(overlay.edges.len(), 3);
+        assert!(!overlay.edges.contains(&(b, c)));
+
+        let mut dag = DiGraph::new();
+        let x = dag.add_node("x");
+        let y = dag.add_node("y");
+        dag.add_edge(x, y);
+        let overlay = cycle_overlay(&dag);
+        assert!(overlay.cycles.is_empty());
+        assert!(overlay.edges.is_empty());
+    }
 }
@@ -75,16 +75,16 @@ pub fn hits(graph: &DiGraph, config: &HITSConfig) -> HITSResult {
         let mut new_hubs = vec![0.0; n];
 
         // Authority update: auth(v) = sum of hub(u) for all u → v
-        for v in 0..n {
+        for (v, auth) in new_auth.iter_mut().enumerate() {
             for &u in graph.predecessors_slice(v) {
-                new_auth[v] += hubs[u];
+                *auth += hubs[u];
             }
         }
 
         // Hub update: hub(u) = sum of auth(v) for all u → v
-        for u in 0..n {
+        for (u, hub) in new_hubs.iter_mut().enumerate() {
             for &v in graph.successors_slice(u) {
-                new_hubs[u] += new_auth[v];
+                *hub += new_auth[v];
             }
         }
 
@@ -85,7 +85,7 @@ pub fn k_critical_paths(graph: &DiGraph, k: usize) -> KPathsResult {
     let mut candidates: Vec<(usize, usize)> = (0..n).map(|v| (v, dist[v])).collect();
 
     // Sort by distance descending
-    candidates.sort_by(|a, b| b.1.cmp(&a.1));
+    candidates.sort_by_key(|c| std::cmp::Reverse(c.1));
     candidates.truncate(k);
 
     // Find max length
@@ -4,6 +4,7 @@
 
 pub mod articulation;
 pub mod betweenness;
+pub mod community;
 pub mod coverage;
 pub mod critical_path;
 pub mod cycles;
@@ -13,6 +14,9 @@ pub mod k_paths;
 pub mod kcore;
 pub mod pagerank;
 pub mod parallel_cut;
+pub mod ranking;
+pub mod ready;
+pub mod schedule;
 pub mod slack;
 pub mod subgraph;
 pub mod topo;
@@ -93,7 +93,7 @@ pub fn parallel_cut_suggestions(
         .collect();
 
     // Sort by parallel gain descending
-    suggestions.sort_by(|a, b| b.parallel_gain.cmp(&a.parallel_gain));
+    suggestions.sort_by_key(|s| std::cmp::Reverse(s.parallel_gain));
     suggestions.truncate(limit);
 
     ParallelCutResult {
@@ -132,7 +132,7 @@ pub fn unblock_ranking(graph: &DiGraph, closed_set: &[bool], limit: usize) -> Ve
         })
         .collect();
 
-    ranking.sort_by(|a, b| b.1.cmp(&a.1));
+    ranking.sort_by_key(|r| std::cmp::Reverse(r.1));
     ranking.truncate(limit);
     ranking
 }
@@ -0,0 +1,368 @@
+//! Metric rankings for dashboards and list sorting.
+//!
+//! Turns per-node score vectors into ranked top-N lists with node IDs
+//! attached, so a dashboard can show the leaders for several metrics
+//! side by side and jump straight to any listed issue. The same scores
+//! back sorting the issue list by computed graph metrics, and bucketing
+//! them drives metric-based node coloring.
+
+use crate::algorithms::betweenness::{betweenness_approx, recommend_sample_size};
+use crate::algorithms::critical_path::critical_path_heights;
+use crate::algorithms::pagerank::pagerank_default;
+use crate::algorithms::slack::slack;
+use crate::graph::DiGraph;
+use serde::Serialize;
+
+/// Seed for betweenness sampling so dashboard rankings are stable across refreshes.
+const BETWEENNESS_SEED: u64 = 42;
+
+/// A single ranked node.
+#[derive(Debug, Clone, Serialize)]
+pub struct RankedNode {
+    /// Node index in the graph
+    pub node: usize,
+    /// Node ID for display
+    pub id: Option<String>,
+    /// Metric value
+    pub score: f64,
+}
+
+/// Top-N lists for the metrics dashboard.
+#[derive(Debug, Clone, Serialize)]
+pub struct DashboardResult {
+    /// Highest PageRank first
+    pub pagerank: Vec<RankedNode>,
+    /// Highest betweenness first (sampled for large graphs)
+    pub betweenness: Vec<RankedNode>,
+    /// Most dependents first
+    pub in_degree: Vec<RankedNode>,
+    /// Least slack first (closest to the critical path)
+    pub slack: Vec<RankedNode>,
+}
+
+/// Rank nodes by score and keep the top `limit`.
+///
+/// Ties are broken by node index so output is deterministic.
+/// NaN scores sort last regardless of direction.
+///
+/// # Arguments
+/// * `graph` - The graph (used to attach node IDs)
+/// * `scores` - Per-node scores indexed by node
+/// * `limit` - Maximum number of entries to return
+/// * `descending` - true for highest-first, false for lowest-first
+pub fn top_n(graph: &DiGraph, scores: &[f64], limit: usize, descending: bool) -> Vec<RankedNode> {
+    let mut order: Vec<usize> = (0..scores.len()).collect();
+    order.sort_by(|&a, &b| {
+        let (sa, sb) = (scores[a], scores[b]);
+        match (sa.is_nan(), sb.is_nan()) {
+            (true, true) => a.cmp(&b),
+            (true, false) => std::cmp::Ordering::Greater,
+            (false, true) => std::cmp::Ordering::Less,
+            (false, false) => {
+                let ord = if descending {
+                    sb.partial_cmp(&sa)
+                } else {
+                    sa.partial_cmp(&sb)
+                };
+                ord.unwrap_or(std::cmp::Ordering::Equal).then(a.cmp(&b))
+            }
+        }
+    });
+
+    order
+        .into_iter()
+        .take(limit)
+        .map(|node| RankedNode {
+            node,
+            id: graph.node_id(node),
+            score: scores[node],
+        })
+        .collect()
+}
+
+/// Compute the top-N lists shown on the metrics dashboard.
+///
+/// Betweenness uses sampling above 100 nodes (see `recommend_sample_size`).
+/// Slack is all zeros for cyclic graphs, so that list degrades to index order.
+pub fn metrics_dashboard(graph: &DiGraph, limit: usize) -> DashboardResult {
+    let n = graph.len();
+
+    let pr = pagerank_default(graph);
+    let bc = betweenness_approx(graph, recommend_sample_size(n), Some(BETWEENNESS_SEED));
+    let in_deg: Vec<f64> = (0..n).map(|v| graph.in_degree(v) as f64).collect();
+    let sl = slack(graph);
+
+    DashboardResult {
+        pagerank: top_n(graph, &pr, limit, true),
+        betweenness: top_n(graph, &bc, limit, true),
+        in_degree: top_n(graph, &in_deg, limit, true),
+        slack: top_n(graph, &sl, limit, false),
+    }
+}
+
+/// Graph metric usable as a list sort key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    PageRank,
+    Betweenness,
+    Slack,
+    /// Open dependents this issue is currently holding up
+    BlockedCount,
+    /// Dependency depth (critical path height)
+    Depth,
+}
+
+impl Metric {
+    /// Parse a metric name as used by sort commands.
+    pub fn parse(name: &str) -> Option<Metric> {
+        match name.to_ascii_lowercase().replace('-', "_").as_str() {
+            "pagerank" => Some(Metric::PageRank),
+            "betweenness" => Some(Metric::Betweenness),
+            "slack" => Some(Metric::Slack),
+            "blocked_count" | "blocks" => Some(Metric::BlockedCount),
+            "depth" => Some(Metric::Depth),
+            _ => None,
+        }
+    }
+}
+
+/// Compute per-node scores for a metric.
+///
+/// closed_set only affects BlockedCount: closed nodes block nothing and
+/// closed dependents are not counted.
+pub fn metric_scores(graph: &DiGraph, metric: Metric, closed_set: &[bool]) -> Vec<f64> {
+    let n = graph.len();
+    let is_closed = |v: usize| closed_set.get(v).copied().unwrap_or(false);
+    match metric {
+        Metric::PageRank => pagerank_default(graph),
+        Metric::Betweenness => {
+            betweenness_approx(graph, recommend_sample_size(n), Some(BETWEENNESS_SEED))
+        }
+        Metric::Slack => slack(graph),
+        Metric::BlockedCount => (0..n)
+            .map(|v| {
+                if is_closed(v) {
+                    return 0.0;
+                }
+                graph
+                    .successors_slice(v)
+                    .iter()
+                    .filter(|&&w| !is_closed(w))
+                    .count() as f64
+            })
+            .collect(),
+        Metric::Depth => critical_path_heights(graph),
+    }
+}
+
+/// Order all nodes by a computed metric.
+///
+/// Ties are broken by node index so the list order is stable between refreshes.
+pub fn sort_by_metric(
+    graph: &DiGraph,
+    metric: Metric,
+    closed_set: &[bool],
+    descending: bool,
+) -> Vec<usize> {
+    let scores = metric_scores(graph, metric, closed_set);
+    top_n(graph, &scores, scores.len(), descending)
+        .into_iter()
+        .map(|r| r.node)
+        .collect()
+}
+
+/// One color bucket, for the legend.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Bucket {
+    /// Smallest score in the bucket
+    pub min: f64,
+    /// Largest score in the bucket
+    pub max: f64,
+    /// Number of nodes in the bucket
+    pub count: usize,
+}
+
+/// Scores binned for coloring.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScoreBuckets {
+    /// Bucket index per node (lowest scores in bucket 0); None for NaN scores
+    /// or when no buckets were requested
+    pub node_bucket: Vec<Option<usize>>,
+    /// Buckets in ascending score order
+    pub buckets: Vec<Bucket>,
+}
+
+/// Bin scores into up to `count` quantile buckets.
+///
+/// Buckets hold roughly equal numbers of nodes, so a few outliers (typical
+/// for PageRank) do not wash the rest of the graph into one color. Equal
+/// scores always share a bucket, so heavily tied scores yield fewer buckets.
+pub fn score_buckets(scores: &[f64], count: usize) -> ScoreBuckets {
+    let mut sorted: Vec<f64> = scores.iter().copied().filter(|s| !s.is_nan()).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let m = sorted.len();
+    let count = count.min(m);
+
+    // Upper bound of each quantile, merged where ties make them equal
+    let mut bounds: Vec<f64> = (1..=count).map(|k| sorted[k * m / count - 1]).collect();
+    bounds.dedup();
+
+    let bucket_of = |s: f64| {
+        if s.is_nan() || bounds.is_empty() {
+            None
+        } else {
+            Some(bounds.partition_point(|&b| b < s).min(bounds.len() - 1))
+        }
+    };
+    let node_bucket: Vec<Option<usize>> = scores.iter().map(|&s| bucket_of(s)).collect();
+
+    let mut buckets: Vec<Bucket> = bounds
+        .iter()
+        .map(|&max| Bucket {
+            min: max,
+            max,
+            count: 0,
+        })
+        .collect();
+    for (&s, b) in scores.iter().zip(&node_bucket) {
+        if let Some(b) = *b {
+            let bucket = &mut buckets[b];
+            bucket.min = bucket.min.min(s);
+            bucket.count += 1;
+        }
+    }
+    ScoreBuckets {
+        node_bucket,
+        buckets,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn star() -> DiGraph {
+        // hub -> a, b, c ; d isolated
+        let mut graph = DiGraph::new();
+        let hub = graph.add_node("hub");
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_node("d");
+        graph.add_edge(hub, a);
+        graph.add_edge(hub, b);
+        graph.add_edge(hub, c);
+        graph
+    }
+
+    #[test]
+    fn test_top_n_descending_with_ties() {
+        let graph = star();
+        let ranked = top_n(&graph, &[1.0, 3.0, 3.0, 0.5, 2.0], 3, true);
+        let nodes: Vec<usize> = ranked.iter().map(|r| r.node).collect();
+        assert_eq!(nodes, vec![1, 2, 4]);
+        assert_eq!(ranked[0].id, Some("a".to_string()));
+    }
+
+    #[test]
+    fn test_top_n_ascending_nan_last() {
+        let graph = star();
+        let ranked = top_n(&graph, &[f64::NAN, 2.0, 1.0, 0.0, 5.0], 5, false);
+        let nodes: Vec<usize> = ranked.iter().map(|r| r.node).collect();
+        assert_eq!(nodes, vec![3, 2, 1, 4, 0]);
+    }
+
+    #[test]
+    fn test_top_n_limit() {
+        let graph = star();
+        assert!(top_n(&graph, &[1.0; 5], 0, true).is_empty());
+        assert_eq!(top_n(&graph, &[1.0; 5], 10, true).len(), 5);
+    }
+
+    #[test]
+    fn test_metrics_dashboard() {
+        let graph = star();
+        let result = metrics_dashboard(&graph, 2);
+        assert_eq!(result.pagerank.len(), 2);
+        assert_eq!(result.betweenness.len(), 2);
+        // a, b and c each have in-degree 1; ties break by index
+        assert_eq!(result.in_degree[0].node, 1);
+        assert_eq!(result.in_degree[0].score, 1.0);
+        // Star nodes are on the critical path (length 2), d has slack 1
+        assert!(result.slack.iter().all(|r| r.score == 0.0));
+    }
+
+    #[test]
+    fn test_metrics_dashboard_empty() {
+        let graph = DiGraph::new();
+        let result = metrics_dashboard(&graph, 5);
+        assert!(result.pagerank.is_empty());
+        assert!(result.slack.is_empty());
+    }
+
+    #[test]
+    fn test_metric_parse() {
+        assert_eq!(Metric::parse("PageRank"), Some(Metric::PageRank));
+        assert_eq!(Metric::parse("blocked-count"), Some(Metric::BlockedCount));
+        assert_eq!(Metric::parse("depth"), Some(Metric::Depth));
+        assert_eq!(Metric::parse("priority"), None);
+    }
+
+    #[test]
+    fn test_sort_by_blocked_count_respects_closed() {
+        let graph = star();
+        // hub holds up a, b, c
+        assert_eq!(
+            sort_by_metric(&graph, Metric::BlockedCount, &[], true)[0],
+            0
+        );
+
+        // Closing a and b leaves hub blocking only c
+        let closed = vec![false, true, true, false, false];
+        let scores = metric_scores(&graph, Metric::BlockedCount, &closed);
+        assert_eq!(scores, vec![1.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_sort_by_depth() {
+        let graph = star();
+        let order = sort_by_metric(&graph, Metric::Depth, &[], true);
+        // Leaves (depth 2) first in index order, then hub and d (depth 1)
+        assert_eq!(order, vec![1, 2, 3, 0, 4]);
+    }
+
+    #[test]
+    fn test_score_buckets_quantiles() {
+        let scores = [0.1, 0.9, 0.2, 100.0, f64::NAN, 0.3];
+        let result = score_buckets(&scores, 2);
+        assert_eq!(
+            result.node_bucket,
+            vec![Some(0), Some(1), Some(0), Some(1), None, Some(1)]
+        );
+        assert_eq!(
+            result.buckets[1],
+            Bucket {
+                min: 0.3,
+                max: 100.0,
+                count: 3
+            }
+        );
+    }
+
+    #[test]
+    fn test_score_buckets_ties_and_empty() {
+        let result = score_buckets(&[1.0, 1.0, 1.0, 2.0], 4);
+        assert_eq!(result.buckets.len(), 2);
+        assert_eq!(result.buckets[0].count, 3);
+        assert!(score_buckets(&[], 5).buckets.is_empty());
+        assert_eq!(score_buckets(&[1.0], 0).node_bucket, vec![None]);
+    }
+
+    #[test]
+    fn test_sort_by_slack_ascending() {
+        let graph = star();
+        let order = sort_by_metric(&graph, Metric::Slack, &[], false);
+        // d is the only node off the critical path
+        assert_eq!(*order.last().unwrap(), 4);
+    }
+}
@@ -0,0 +1,426 @@
+//! Ready-work ranking: "what should the team pick up next".
+//!
+//! Lists open issues whose blockers are all closed and orders them by a
+//! weighted blend of four normalized signals:
+//! - priority (P0 highest),
+//! - urgency from slack in the remaining (open) work,
+//! - downstream impact, the PageRank mass of the issue's direct dependents,
+//! - unblocks, the number of open issues downstream of it.
+//!
+//! The default weights make this the ready view's ordering; callers can
+//! pass their own weights to match a project's priorities.
+
+use crate::algorithms::cycles::{is_cycle_component, tarjan_scc};
+use crate::algorithms::pagerank::pagerank_default;
+use crate::algorithms::slack::slack;
+use crate::algorithms::subgraph::extract_subgraph;
+use crate::graph::DiGraph;
+use crate::reachability::is_actionable;
+use serde::Serialize;
+
+/// Lowest priority value (P4); P0 is the highest.
+const MAX_PRIORITY: f64 = 4.0;
+
+/// Priority used when none is given.
+const DEFAULT_PRIORITY: f64 = 2.0;
+
+/// Blend weights for the ready-work score. Weights need not sum to 1.
+#[derive(Debug, Clone, Copy)]
+pub struct ReadyWeights {
+    pub priority: f64,
+    pub slack: f64,
+    pub impact: f64,
+    pub unblocks: f64,
+}
+
+impl Default for ReadyWeights {
+    fn default() -> Self {
+        ReadyWeights {
+            priority: 0.4,
+            slack: 0.2,
+            impact: 0.2,
+            unblocks: 0.2,
+        }
+    }
+}
+
+/// A ready issue with its score breakdown (each component in [0, 1]).
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadyItem {
+    /// Node index in the graph
+    pub node: usize,
+    /// Node ID for display
+    pub id: Option<String>,
+    /// Weighted blend of the components below
+    pub score: f64,
+    /// 1 for P0, 0 for P4
+    pub priority: f64,
+    /// 1 for zero slack (critical), 0 for the most slack among open work
+    pub urgency: f64,
+    /// Dependents' PageRank mass relative to the largest among ready issues
+    pub impact: f64,
+    /// Open transitive dependents relative to the most among ready issues
+    pub unblocks: f64,
+    /// Number of open transitive dependents
+    pub downstream: usize,
+}
+
+/// Normalize a priority value to [0, 1] with P0 -> 1.
+fn priority_component(priorities: &[f64], node: usize) -> f64 {
+    let p = priorities
+        .get(node)
+        .copied()
+        .filter(|p| p.is_finite())
+        .unwrap_or(DEFAULT_PRIORITY)
+        .clamp(0.0, MAX_PRIORITY);
+    (MAX_PRIORITY - p) / MAX_PRIORITY
+}
+
+/// Count the open transitive dependents of every node in one pass.
+///
+/// Walks the SCC condensation sinks first (the order Tarjan emits it),
+/// merging each component's bitset of reachable open nodes into its
+/// predecessors. Cost is O((V + E) * open / 64) time instead of a BFS per
+/// node.
+///
+/// A component's bitset is freed as soon as its last predecessor has merged
+/// it, so only the sets still awaited by an unprocessed predecessor are
+/// live: peak memory is that frontier's width times open / 64 words. That
+/// is small for chains and trees; the worst case is a graph where one late
+/// component blocks nearly everything else, which keeps all sets alive.
+fn open_downstream_counts(graph: &DiGraph, closed_set: &[bool]) -> Vec<usize> {
+    let n = graph.len();
+    let mut bit = vec![None; n];
+    let mut open_count = 0usize;
+    for (v, slot) in bit.iter_mut().enumerate() {
+        if !closed_set.get(v).copied().unwrap_or(false) {
+            *slot = Some(open_count);
+            open_count += 1;
+        }
+    }
+    let words = open_count.div_ceil(64);
+    let insert = |set: &mut [u64], v: usize| {
+        if let Some(b) = bit[v] {
+            set[b / 64] |= 1u64 << (b % 64);
+        }
+    };
+
+    let components = tarjan_scc(graph).components;
+    let mut comp_of = vec![0; n];
+    for (c, members) in components.iter().enumerate() {
+        for &v in members {
+            comp_of[v] = c;
+        }
+    }
+
+    // Cross-component edges into each component that have not merged it yet
+    let mut pending = vec![0usize; components.len()];
+    for (u, v) in graph.edges() {
+        if comp_of[u] != comp_of[v] {
+            pending[comp_of[v]] += 1;
+        }
+    }
+
+    let mut reach: Vec<Option<Vec<u64>>> = Vec::with_capacity(components.len());
+    let mut counts = vec![0; n];
+    for (c, members) in components.iter().enumerate() {
+        let mut set = vec![0u64; words];
+        let cyclic = is_cycle_component(graph, members);
+        for &v in members {
+            if cyclic {
+                insert(&mut set, v);
+            }
+            for &w in graph.successors_slice(v) {
+                let d = comp_of[w];
+                if d != c {
+                    // Components reachable from c were emitted before it
+                    if let Some(other) = &reach[d] {
+                        for (word, &bits) in set.iter_mut().zip(other) {
+                            *word |= bits;
+                        }
+                    }
+                    insert(&mut set, w);
+                    pending[d] -= 1;
+                    if pending[d] == 0 {
+                        reach[d] = None;
+                    }
+                }
+            }
+        }
+        let total: usize = set.iter().map(|w| w.count_ones() as usize).sum();
+        for &v in members {
+            // A node on a cycle reaches itself but is not its own dependent
+            counts[v] = total - usize::from(cyclic && bit[v].is_some());
+        }
+        // Sources have no predecessors left to merge their set
+        reach.push((pending[c] > 0).then_some(set));
+    }
+    counts
+}
+
+/// Rank ready (actionable, open) issues by the blended score.
+///
+/// Ties are broken by node index. Slack is measured on the open subgraph,
+/// so finished work does not lengthen the chains; it is all zeros when the
+/// open work is cyclic, which makes urgency uniform. Downstream counts are
+/// computed for all nodes at once (see `open_downstream_counts`).
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `closed_set` - Boolean array indicating which nodes are closed
+/// * `priorities` - Per-node priority (0 = P0); missing entries default to P2
+/// * `weights` - Blend weights
+pub fn ready_work(
+    graph: &DiGraph,
+    closed_set: &[bool],
+    priorities: &[f64],
+    weights: ReadyWeights,
+) -> Vec<ReadyItem> {
+    let n = graph.len();
+    let is_closed = |v: usize| closed_set.get(v).copied().unwrap_or(false);
+    let ready: Vec<usize> = (0..n)
+        .filter(|&v| !is_closed(v) && is_actionable(graph, v, closed_set))
+        .collect();
+    if ready.is_empty() {
+        return Vec::new();
+    }
+
+    // Slack on open work; extract_subgraph renumbers in input order
+    let open: Vec<usize> = (0..n).filter(|&v| !is_closed(v)).collect();
+    let mut open_slack = vec![0.0; n];
+    for (i, s) in slack(&extract_subgraph(graph, &open))
+        .into_iter()
+        .enumerate()
+    {
+        open_slack[open[i]] = s;
+    }
+    let max_slack = open.iter().map(|&v| open_slack[v]).fold(0.0, f64::max);
+
+    let pr = pagerank_default(graph);
+    let impact_raw: Vec<f64> = ready
+        .iter()
+        .map(|&v| {
+            graph
+                .successors_slice(v)
+                .iter()
+                .filter(|&&w| !is_closed(w))
+                .map(|&w| pr[w])
+                .sum()
+        })
+        .collect();
+    let max_impact = impact_raw.iter().copied().fold(0.0, f64::max);
+
+    let all_downstream = open_downstream_counts(graph, closed_set);
+    let downstream: Vec<usize> = ready.iter().map(|&v| all_downstream[v]).collect();
+    let max_downstream = downstream.iter().copied().max().unwrap_or(0);
+
+    let mut items: Vec<ReadyItem> = ready
+        .iter()
+        .zip(impact_raw)
+        .zip(downstream)
+        .map(|((&v, raw), downstream)| {
+            let priority = priority_component(priorities, v);
+            let urgency = if max_slack > 0.0 {
+                1.0 - open_slack[v] / max_slack
+            } else {
+                1.0
+            };
+            let impact = if max_impact > 0.0 {
+                raw / max_impact
+            } else {
+                0.0
+            };
+            let unblocks = if max_downstream > 0 {
+                downstream as f64 / max_downstream as f64
+            } else {
+                0.0
+            };
+            ReadyItem {
+                node: v,
+                id: graph.node_id(v),
+                score: weights.priority * priority
+                    + weights.slack * urgency
+                    + weights.impact * impact
+                    + weights.unblocks * unblocks,
+                priority,
+                urgency,
+                impact,
+                unblocks,
+                downstream,
+            }
+        })
+        .collect();
+
+    items.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.node.cmp(&b.node))
+    });
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nodes(items: &[ReadyItem]) -> Vec<usize> {
+        items.iter().map(|i| i.node).collect()
+    }
+
+    #[test]
+    fn test_ready_work_empty() {
+        let graph = DiGraph::new();
+        assert!(ready_work(&graph, &[], &[], ReadyWeights::default()).is_empty());
+    }
+
+    #[test]
+    fn test_ready_work_only_actionable() {
+        // a -> b -> c ; d isolated
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let items = ready_work(&graph, &[], &[], ReadyWeights::default());
+        let mut ready = nodes(&items);
+        ready.sort();
+        assert_eq!(ready, vec![a, d]);
+
+        let closed = vec![true, false, false, false];
+        let items = ready_work(&graph, &closed, &[], ReadyWeights::default());
+        let mut ready = nodes(&items);
+        ready.sort();
+        assert_eq!(ready, vec![b, d]);
+    }
+
+    #[test]
+    fn test_ready_work_priority_weight() {
+        let mut graph = DiGraph::new();
+        graph.add_node("p3");
+        graph.add_node("p0");
+        let weights = ReadyWeights {
+            priority: 1.0,
+            slack: 0.0,
+            impact: 0.0,
+            unblocks: 0.0,
+        };
+        let items = ready_work(&graph, &[], &[3.0, 0.0], weights);
+        assert_eq!(nodes(&items), vec![1, 0]);
+        assert_eq!(items[0].priority, 1.0);
+    }
+
+    #[test]
+    fn test_ready_work_slack_and_impact() {
+        // a -> b -> c is the critical chain; d -> e is shorter
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        let e = graph.add_node("e");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(d, e);
+
+        let urgency_only = ReadyWeights {
+            priority: 0.0,
+            slack: 1.0,
+            impact: 0.0,
+            unblocks: 0.0,
+        };
+        let items = ready_work(&graph, &[], &[], urgency_only);
+        assert_eq!(nodes(&items), vec![a, d]);
+        assert_eq!(items[0].urgency, 1.0);
+        assert_eq!(items[1].urgency, 0.0);
+
+        let items = ready_work(&graph, &[], &[], ReadyWeights::default());
+        assert!(items.iter().all(|i| i.impact > 0.0));
+    }
+
+    #[test]
+    fn test_ready_work_unblocks() {
+        // a -> b -> {c, d}; e -> f
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        let e = graph.add_node("e");
+        let f = graph.add_node("f");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(b, d);
+        graph.add_edge(e, f);
+
+        let unblocks_only = ReadyWeights {
+            priority: 0.0,
+            slack: 0.0,
+            impact: 0.0,
+            unblocks: 1.0,
+        };
+        // e is P0 but a opens up more work
+        let items = ready_work(&graph, &[], &[2.0, 2.0, 2.0, 2.0, 0.0, 2.0], unblocks_only);
+        assert_eq!(nodes(&items), vec![a, e]);
+        assert_eq!(items[0].downstream, 3);
+        assert_eq!(items[1].unblocks, 1.0 / 3.0);
+        assert!(!nodes(&items).contains(&f));
+
+        // Closed dependents do not count
+        let closed = vec![false, true, true, true, false, false];
+        let items = ready_work(&graph, &closed, &[], unblocks_only);
+        assert_eq!(nodes(&items), vec![e, a]);
+        assert_eq!(items[0].unblocks, 1.0);
+        assert_eq!(items[1].downstream, 0);
+    }
+
+    /// Reference counts from one BFS per node.
+    fn bfs_counts(graph: &DiGraph, closed: &[bool]) -> Vec<usize> {
+        (0..graph.len())
+            .map(|v| {
+                crate::reachability::reachable_from(graph, v)
+                    .into_iter()
+                    .filter(|&w| w != v && !closed[w])
+                    .count()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_open_downstream_counts_match_bfs() {
+        // a -> b <-> c -> d -> e, a -> e, f -> f, b closed
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e", "f"] {
+            graph.add_node(id);
+        }
+        for (u, v) in [(0, 1), (1, 2), (2, 1), (2, 3), (3, 4), (0, 4), (5, 5)] {
+            graph.add_edge(u, v);
+        }
+        let closed = vec![false, true, false, false, false, false];
+
+        let counts = open_downstream_counts(&graph, &closed);
+        assert_eq!(counts, bfs_counts(&graph, &closed));
+        assert_eq!(counts, vec![3, 3, 2, 1, 0, 0]);
+
+        // Layered DAG where sets are freed while others are still pending
+        let mut dag = DiGraph::new();
+        for i in 0..12 {
+            dag.add_node(&format!("n{}", i));
+        }
+        for v in 0..12 {
+            for w in [v + 1, v + 3, v * 2 + 1] {
+                if w < 12 && w != v {
+                    dag.add_edge(v, w);
+                }
+            }
+        }
+        let closed: Vec<bool> = (0..12).map(|v| v % 4 == 1).collect();
+        assert_eq!(
+            open_downstream_counts(&dag, &closed),
+            bfs_counts(&dag, &closed)
+        );
+    }
+}
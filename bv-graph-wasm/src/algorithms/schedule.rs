@@ -0,0 +1,349 @@
+//! Critical Path Method (CPM) scheduling.
+//!
+//! Computes earliest/latest start and finish times for every node using
+//! per-node durations (estimates). Feeds timeline (Gantt) rendering:
+//! bars are laid out from earliest start to earliest finish, and slack is
+//! the window between earliest and latest start.
+
+use crate::algorithms::subgraph::extract_subgraph;
+use crate::algorithms::topo::topological_sort;
+use crate::graph::DiGraph;
+use serde::Serialize;
+
+/// Duration used for nodes without a (valid) estimate.
+pub const DEFAULT_DURATION: f64 = 1.0;
+
+/// Schedule timings for a single node.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleEntry {
+    /// Node index in the graph
+    pub node: usize,
+    /// Duration used for this node
+    pub duration: f64,
+    /// Earliest time the node can start (all blockers finished)
+    pub earliest_start: f64,
+    /// Earliest time the node can finish
+    pub earliest_finish: f64,
+    /// Latest start that does not delay the project
+    pub latest_start: f64,
+    /// Latest finish that does not delay the project
+    pub latest_finish: f64,
+    /// Float available before the project end moves (latest - earliest start)
+    pub slack: f64,
+    /// Whether the node has zero slack
+    pub critical: bool,
+}
+
+/// Result of CPM scheduling.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleResult {
+    /// Per-node timings in node index order (empty for cyclic graphs)
+    pub entries: Vec<ScheduleEntry>,
+    /// Total project duration (maximum earliest finish)
+    pub project_duration: f64,
+    /// One critical chain in execution order (source to sink)
+    pub critical_path: Vec<usize>,
+    /// Whether the graph is a DAG (scheduling requires one)
+    pub is_dag: bool,
+}
+
+/// Resolve the duration for a node, falling back to DEFAULT_DURATION
+/// when the estimate is missing, negative, or not finite.
+pub(crate) fn node_duration(durations: &[f64], node: usize) -> f64 {
+    durations
+        .get(node)
+        .copied()
+        .filter(|d| d.is_finite() && *d >= 0.0)
+        .unwrap_or(DEFAULT_DURATION)
+}
+
+/// Compute a CPM schedule for the graph.
+///
+/// # Algorithm
+/// 1. Topological sort
+/// 2. Forward pass: ES[v] = max(EF[u]) over blockers u, EF[v] = ES[v] + d[v]
+/// 3. Backward pass: LF[v] = min(LS[w]) over dependents w, LS[v] = LF[v] - d[v]
+/// 4. Slack = LS - ES; zero-slack nodes are critical
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `durations` - Per-node durations indexed by node (missing entries use DEFAULT_DURATION)
+///
+/// # Returns
+/// ScheduleResult with per-node timings. Returns an empty schedule for cyclic graphs.
+pub fn cpm_schedule(graph: &DiGraph, durations: &[f64]) -> ScheduleResult {
+    let n = graph.len();
+    let order = match topological_sort(graph) {
+        Some(o) => o,
+        None => {
+            return ScheduleResult {
+                entries: Vec::new(),
+                project_duration: 0.0,
+                critical_path: Vec::new(),
+                is_dag: false,
+            }
+        }
+    };
+
+    let duration: Vec<f64> = (0..n).map(|v| node_duration(durations, v)).collect();
+
+    // Forward pass
+    let mut es = vec![0.0; n];
+    let mut ef = vec![0.0; n];
+    for &v in &order {
+        es[v] = graph
+            .predecessors_slice(v)
+            .iter()
+            .map(|&u| ef[u])
+            .fold(0.0, f64::max);
+        ef[v] = es[v] + duration[v];
+    }
+
+    let project_duration = ef.iter().cloned().fold(0.0, f64::max);
+
+    // Backward pass
+    let mut lf = vec![project_duration; n];
+    let mut ls = vec![0.0; n];
+    for &v in order.iter().rev() {
+        lf[v] = graph
+            .successors_slice(v)
+            .iter()
+            .map(|&w| ls[w])
+            .fold(project_duration, f64::min);
+        ls[v] = lf[v] - duration[v];
+    }
+
+    let entries: Vec<ScheduleEntry> = (0..n)
+        .map(|v| {
+            let slack = (ls[v] - es[v]).max(0.0);
+            ScheduleEntry {
+                node: v,
+                duration: duration[v],
+                earliest_start: es[v],
+                earliest_finish: ef[v],
+                latest_start: ls[v],
+                latest_finish: lf[v],
+                slack,
+                critical: slack < 1e-9,
+            }
+        })
+        .collect();
+
+    let critical_path = trace_critical_chain(graph, &order, &entries);
+
+    ScheduleResult {
+        entries,
+        project_duration,
+        critical_path,
+        is_dag: true,
+    }
+}
+
+/// Walk one zero-slack chain from a critical source to a critical sink.
+///
+/// Prefers the lowest node index at each step for deterministic output.
+fn trace_critical_chain(graph: &DiGraph, order: &[usize], entries: &[ScheduleEntry]) -> Vec<usize> {
+    let start = order
+        .iter()
+        .copied()
+        .find(|&v| entries[v].critical && entries[v].earliest_start < 1e-9);
+
+    let mut chain = Vec::new();
+    let mut current = match start {
+        Some(v) => v,
+        None => return chain,
+    };
+
+    loop {
+        chain.push(current);
+        let finish = entries[current].earliest_finish;
+        let next = graph
+            .successors_slice(current)
+            .iter()
+            .copied()
+            .filter(|&w| entries[w].critical && (entries[w].earliest_start - finish).abs() < 1e-9)
+            .min();
+        match next {
+            Some(w) => current = w,
+            None => break,
+        }
+    }
+
+    chain
+}
+
+/// Estimate totals for the remaining work.
+#[derive(Debug, Clone, Serialize)]
+pub struct EstimateTotals {
+    /// Sum of estimates over open nodes
+    pub open_estimate: f64,
+    /// Sum of estimates along the critical chain of open work
+    pub critical_path_estimate: f64,
+    /// One estimate-weighted critical chain of open work, source to sink
+    pub critical_path: Vec<usize>,
+    /// Open nodes without a valid estimate (counted as 0)
+    pub unestimated: Vec<usize>,
+    /// Whether the open work is a DAG (the critical path is empty otherwise)
+    pub is_dag: bool,
+}
+
+/// Sum estimates over open work and along its critical chain.
+///
+/// Unlike `cpm_schedule`, missing estimates count as 0 rather than
+/// DEFAULT_DURATION, so totals only add up what was actually estimated;
+/// `unestimated` lists the gaps. Per-epic totals come from the hierarchy
+/// rollup's `subtree_estimate`.
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `closed_set` - Boolean array indicating which nodes are closed
+/// * `estimates` - Per-node estimates (missing, negative or non-finite = unestimated)
+pub fn estimate_totals(graph: &DiGraph, closed_set: &[bool], estimates: &[f64]) -> EstimateTotals {
+    let open: Vec<usize> = (0..graph.len())
+        .filter(|&v| !closed_set.get(v).copied().unwrap_or(false))
+        .collect();
+    let estimate = |v: usize| {
+        estimates
+            .get(v)
+            .copied()
+            .filter(|e| e.is_finite() && *e >= 0.0)
+    };
+    let unestimated: Vec<usize> = open
+        .iter()
+        .copied()
+        .filter(|&v| estimate(v).is_none())
+        .collect();
+    let durations: Vec<f64> = open.iter().map(|&v| estimate(v).unwrap_or(0.0)).collect();
+
+    // extract_subgraph renumbers in input order, so sub index i == open[i]
+    let schedule = cpm_schedule(&extract_subgraph(graph, &open), &durations);
+    EstimateTotals {
+        open_estimate: durations.iter().sum(),
+        critical_path_estimate: schedule.project_duration,
+        critical_path: schedule.critical_path.iter().map(|&i| open[i]).collect(),
+        unestimated,
+        is_dag: schedule.is_dag,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schedule_empty() {
+        let graph = DiGraph::new();
+        let result = cpm_schedule(&graph, &[]);
+        assert!(result.entries.is_empty());
+        assert!(result.is_dag);
+        assert_eq!(result.project_duration, 0.0);
+    }
+
+    #[test]
+    fn test_schedule_chain() {
+        // a(2) -> b(3) -> c(1)
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let result = cpm_schedule(&graph, &[2.0, 3.0, 1.0]);
+        assert_eq!(result.project_duration, 6.0);
+        assert_eq!(result.entries[b].earliest_start, 2.0);
+        assert_eq!(result.entries[c].earliest_finish, 6.0);
+        assert!(result.entries.iter().all(|e| e.critical));
+        assert_eq!(result.critical_path, vec![a, b, c]);
+    }
+
+    #[test]
+    fn test_schedule_parallel_branches() {
+        //   a(1) -> b(5) -> d(1)
+        //   a(1) -> c(2) -> d(1)
+        // c has 3 units of slack
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, d);
+        graph.add_edge(c, d);
+
+        let result = cpm_schedule(&graph, &[1.0, 5.0, 2.0, 1.0]);
+        assert_eq!(result.project_duration, 7.0);
+        assert_eq!(result.entries[c].slack, 3.0);
+        assert_eq!(result.entries[c].latest_start, 4.0);
+        assert!(!result.entries[c].critical);
+        assert_eq!(result.critical_path, vec![a, b, d]);
+    }
+
+    #[test]
+    fn test_schedule_default_durations() {
+        // Missing and invalid estimates fall back to DEFAULT_DURATION
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+
+        let result = cpm_schedule(&graph, &[f64::NAN]);
+        assert_eq!(result.entries[a].duration, DEFAULT_DURATION);
+        assert_eq!(result.entries[b].duration, DEFAULT_DURATION);
+        assert_eq!(result.project_duration, 2.0);
+    }
+
+    #[test]
+    fn test_schedule_cyclic() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        let result = cpm_schedule(&graph, &[1.0, 1.0]);
+        assert!(!result.is_dag);
+        assert!(result.entries.is_empty());
+    }
+
+    #[test]
+    fn test_schedule_isolated_node_slack() {
+        // a(4) alone, b(1) alone: b can slide by 3
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+
+        let result = cpm_schedule(&graph, &[4.0, 1.0]);
+        assert_eq!(result.entries[a].slack, 0.0);
+        assert_eq!(result.entries[b].slack, 3.0);
+        assert_eq!(result.critical_path, vec![a]);
+    }
+
+    #[test]
+    fn test_estimate_totals() {
+        // a(3, closed) -> b(2) -> c(?), b -> d(5)
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(b, d);
+
+        let closed = vec![true, false, false, false];
+        let totals = estimate_totals(&graph, &closed, &[3.0, 2.0, f64::NAN, 5.0]);
+        assert_eq!(totals.open_estimate, 7.0);
+        assert_eq!(totals.critical_path_estimate, 7.0);
+        assert_eq!(totals.critical_path, vec![b, d]);
+        assert_eq!(totals.unestimated, vec![c]);
+        assert!(totals.is_dag);
+
+        graph.add_edge(d, b);
+        let totals = estimate_totals(&graph, &closed, &[3.0, 2.0]);
+        assert!(!totals.is_dag);
+        assert!(totals.critical_path.is_empty());
+        assert_eq!(totals.open_estimate, 2.0);
+    }
+}
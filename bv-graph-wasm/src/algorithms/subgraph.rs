@@ -54,6 +54,27 @@ pub fn extract_subgraph(graph: &DiGraph, node_indices: &[usize]) -> DiGraph {
     new_graph
 }
 
+/// Original index of every node of `extract_subgraph(graph, node_indices)`.
+///
+/// Entry `i` is the source-graph index of subgraph node `i`: the input
+/// order with out-of-range and repeated indices dropped. Views rendering a
+/// subgraph use it to map selections and metrics back to the full graph.
+pub fn subgraph_mapping(graph: &DiGraph, node_indices: &[usize]) -> Vec<usize> {
+    let mut seen = vec![false; graph.len()];
+    node_indices
+        .iter()
+        .copied()
+        .filter(|&v| v < seen.len() && !std::mem::replace(&mut seen[v], true))
+        .collect()
+}
+
+/// Extract the dependency cone of a node as a subgraph.
+///
+/// The mapping back to the source graph is `dependency_cone(graph, node)`.
+pub fn cone_subgraph(graph: &DiGraph, node: usize) -> DiGraph {
+    extract_subgraph(graph, &dependency_cone(graph, node))
+}
+
 /// Extract a subgraph by node IDs (string lookup).
 ///
 /// Convenience wrapper that looks up indices by ID string first.
@@ -359,6 +380,32 @@ mod tests {
         assert!(is_dag(&sub));
     }
 
+    #[test]
+    fn test_subgraph_mapping() {
+        // a -> b -> c, d isolated
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let indices = [c, 99, a, c, b];
+        let mapping = subgraph_mapping(&graph, &indices);
+        assert_eq!(mapping, vec![c, a, b]);
+        let sub = extract_subgraph(&graph, &indices);
+        for (i, &old) in mapping.iter().enumerate() {
+            assert_eq!(sub.node_id(i), graph.node_id(old));
+        }
+
+        let cone = cone_subgraph(&graph, b);
+        assert_eq!(cone.node_count(), 3);
+        assert_eq!(cone.edge_count(), 2);
+        assert_eq!(cone.node_idx("d"), None);
+        assert_eq!(dependency_cone(&graph, d), vec![d]);
+    }
+
     #[test]
     fn test_subgraph_invalid_indices() {
         let mut graph = DiGraph::new();
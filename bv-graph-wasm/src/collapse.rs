@@ -0,0 +1,180 @@
+//! Hidden nodes and collapsed groups for the graph view.
+//!
+//! Users hide noisy issues (or whole subtrees of dependents) and collapse
+//! hand-picked sets into a single
+//! labeled group node. The view then renders a reduced graph whose edges
+//! are the original edges re-pointed at the group nodes, merged and
+//! without self-loops. Pinning is pure layout state and stays in the view.
+
+use crate::graph::DiGraph;
+use crate::reachability::reachable_from;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// A manually collapsed set of nodes.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NodeGroup {
+    /// Label shown on the group node
+    pub label: String,
+    /// Member node indices
+    pub nodes: Vec<usize>,
+}
+
+/// One node of the reduced view.
+#[derive(Debug, Clone, Serialize)]
+pub struct ViewNode {
+    /// Issue ID, or the group label for group nodes
+    pub id: String,
+    /// Whether this node stands for a collapsed group
+    pub group: bool,
+    /// Original node indices it represents
+    pub members: Vec<usize>,
+}
+
+/// The reduced graph the view renders.
+#[derive(Debug, Clone, Serialize)]
+pub struct CollapsedView {
+    /// View nodes; indices into this list are used by `edges`
+    pub nodes: Vec<ViewNode>,
+    /// Distinct (blocker, blocked) edges between view nodes
+    pub edges: Vec<(usize, usize)>,
+}
+
+/// Build the reduced view.
+///
+/// Hiding wins over grouping, and a node listed in several groups belongs
+/// to the first. Nodes keep index order; a group takes the place of its
+/// first visible member, and groups with no visible members are dropped.
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `hidden` - Nodes to leave out entirely
+/// * `hidden_subtrees` - Roots to leave out together with everything
+///   downstream of them (their transitive dependents)
+/// * `groups` - Sets to collapse into one node each
+pub fn collapse_view(
+    graph: &DiGraph,
+    hidden: &[usize],
+    hidden_subtrees: &[usize],
+    groups: &[NodeGroup],
+) -> CollapsedView {
+    let n = graph.len();
+    let mut is_hidden = vec![false; n];
+    for &v in hidden.iter().filter(|&&v| v < n) {
+        is_hidden[v] = true;
+    }
+    for &root in hidden_subtrees {
+        for v in reachable_from(graph, root) {
+            is_hidden[v] = true;
+        }
+    }
+    let mut group_of: Vec<Option<usize>> = vec![None; n];
+    for (g, group) in groups.iter().enumerate() {
+        for &v in group.nodes.iter().filter(|&&v| v < n) {
+            if !is_hidden[v] && group_of[v].is_none() {
+                group_of[v] = Some(g);
+            }
+        }
+    }
+
+    let mut nodes: Vec<ViewNode> = Vec::new();
+    let mut view_of: Vec<Option<usize>> = vec![None; n];
+    let mut group_view: Vec<Option<usize>> = vec![None; groups.len()];
+    for v in (0..n).filter(|&v| !is_hidden[v]) {
+        let slot = match group_of[v] {
+            Some(g) => *group_view[g].get_or_insert_with(|| {
+                nodes.push(ViewNode {
+                    id: groups[g].label.clone(),
+                    group: true,
+                    members: Vec::new(),
+                });
+                nodes.len() - 1
+            }),
+            None => {
+                nodes.push(ViewNode {
+                    id: graph.node_id(v).unwrap_or_default(),
+                    group: false,
+                    members: Vec::new(),
+                });
+                nodes.len() - 1
+            }
+        };
+        nodes[slot].members.push(v);
+        view_of[v] = Some(slot);
+    }
+
+    let mut seen = HashSet::new();
+    let edges = graph
+        .edges()
+        .filter_map(|(u, v)| Some((view_of[u]?, view_of[v]?)))
+        .filter(|&(a, b)| a != b && seen.insert((a, b)))
+        .collect();
+    CollapsedView { nodes, edges }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -> b -> d, a -> c -> d, e -> d
+    fn diamond() -> DiGraph {
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c", "d", "e"] {
+            graph.add_node(id);
+        }
+        graph.add_edge(0, 1);
+        graph.add_edge(0, 2);
+        graph.add_edge(1, 3);
+        graph.add_edge(2, 3);
+        graph.add_edge(4, 3);
+        graph
+    }
+
+    #[test]
+    fn test_collapse_view_identity() {
+        let graph = diamond();
+        let view = collapse_view(&graph, &[], &[], &[]);
+        assert_eq!(view.nodes.len(), 5);
+        assert_eq!(view.edges.len(), 5);
+        assert_eq!(view.nodes[3].members, vec![3]);
+    }
+
+    #[test]
+    fn test_collapse_view_group_and_hide() {
+        let graph = diamond();
+        let groups = vec![NodeGroup {
+            label: "middle".to_string(),
+            nodes: vec![2, 1, 99],
+        }];
+        let view = collapse_view(&graph, &[4], &[], &groups);
+        let ids: Vec<&str> = view.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "middle", "d"]);
+        assert!(view.nodes[1].group);
+        assert_eq!(view.nodes[1].members, vec![1, 2]);
+        // a -> b and a -> c merge, as do b -> d and c -> d; e is gone
+        assert_eq!(view.edges, vec![(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_collapse_view_group_edges_inside_dropped() {
+        let graph = diamond();
+        let groups = vec![NodeGroup {
+            label: "all".to_string(),
+            nodes: (0..5).collect(),
+        }];
+        let view = collapse_view(&graph, &[], &[], &groups);
+        assert_eq!(view.nodes.len(), 1);
+        assert!(view.edges.is_empty());
+    }
+
+    #[test]
+    fn test_collapse_view_hide_subtree() {
+        // Hiding b drops its dependent d as well; a, c and e remain
+        let graph = diamond();
+        let view = collapse_view(&graph, &[], &[1], &[]);
+        let ids: Vec<&str> = view.nodes.iter().map(|n| n.id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "c", "e"]);
+        assert_eq!(view.edges, vec![(0, 1)]);
+        assert_eq!(collapse_view(&graph, &[], &[99], &[]).nodes.len(), 5);
+    }
+}
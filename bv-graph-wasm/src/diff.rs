@@ -0,0 +1,237 @@
+//! Issue-level diff between two snapshots of a project.
+//!
+//! Compares a base graph (e.g. an older Dolt commit or HEAD) with a target
+//! graph (a later commit or the working set) by issue ID: which issues were
+//! added or removed, which fields changed, and which dependency edges
+//! appeared or disappeared. Output is ordered by ID so it reads like a
+//! structured `git diff`.
+
+use crate::filter::IssueRecord;
+use crate::graph::DiGraph;
+use serde::Serialize;
+use std::collections::BTreeSet;
+
+/// A single field that differs between base and target.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct FieldChange {
+    /// Field name (matches the IssueRecord field)
+    pub field: &'static str,
+    /// Value in the base snapshot
+    pub old: String,
+    /// Value in the target snapshot
+    pub new: String,
+}
+
+/// Field changes for one issue present in both snapshots.
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueChange {
+    /// Issue ID
+    pub id: String,
+    /// Changed fields in a fixed field order
+    pub fields: Vec<FieldChange>,
+}
+
+/// Full diff between two snapshots.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct SnapshotDiff {
+    /// IDs present only in the target
+    pub added: Vec<String>,
+    /// IDs present only in the base
+    pub removed: Vec<String>,
+    /// Issues present in both with differing fields
+    pub changed: Vec<IssueChange>,
+    /// Dependency edges (blocker, blocked) present only in the target
+    pub edges_added: Vec<(String, String)>,
+    /// Dependency edges (blocker, blocked) present only in the base
+    pub edges_removed: Vec<(String, String)>,
+}
+
+/// Compare the user-visible fields of two records.
+///
+/// `updated_at` is skipped: it changes on every write and would otherwise
+/// flag each edited issue twice. Labels compare as a set, so reordering
+/// them is not a change.
+pub fn field_changes(old: &IssueRecord, new: &IssueRecord) -> Vec<FieldChange> {
+    let priority = |p: Option<i64>| p.map(|p| p.to_string()).unwrap_or_default();
+    let estimate = |e: Option<f64>| e.map(|e| e.to_string()).unwrap_or_default();
+    let labels = |l: &[String]| {
+        let mut l = l.to_vec();
+        l.sort_unstable();
+        l.dedup();
+        l.join(",")
+    };
+    let pairs = [
+        ("title", old.title.clone(), new.title.clone()),
+        ("status", old.status.clone(), new.status.clone()),
+        ("priority", priority(old.priority), priority(new.priority)),
+        ("issue_type", old.issue_type.clone(), new.issue_type.clone()),
+        ("assignee", old.assignee.clone(), new.assignee.clone()),
+        ("labels", labels(&old.labels), labels(&new.labels)),
+        ("estimate", estimate(old.estimate), estimate(new.estimate)),
+        ("created_at", old.created_at.clone(), new.created_at.clone()),
+    ];
+    pairs
+        .into_iter()
+        .filter(|(_, o, n)| o != n)
+        .map(|(field, old, new)| FieldChange { field, old, new })
+        .collect()
+}
+
+/// Diff two snapshots by issue ID.
+///
+/// Records are parallel to each graph's node indices. If either record
+/// slice is too short for a node, that node's fields are not compared.
+///
+/// # Arguments
+/// * `base` - The earlier snapshot
+/// * `base_records` - Issue records for `base`
+/// * `target` - The later snapshot
+/// * `target_records` - Issue records for `target`
+pub fn snapshot_diff(
+    base: &DiGraph,
+    base_records: &[IssueRecord],
+    target: &DiGraph,
+    target_records: &[IssueRecord],
+) -> SnapshotDiff {
+    let mut diff = SnapshotDiff::default();
+
+    let mut base_ids: Vec<(String, usize)> = (0..base.len())
+        .filter_map(|v| base.node_id(v).map(|id| (id, v)))
+        .collect();
+    base_ids.sort();
+
+    for (id, v) in &base_ids {
+        match target.node_idx(id) {
+            None => diff.removed.push(id.clone()),
+            Some(w) => {
+                if let (Some(old), Some(new)) = (base_records.get(*v), target_records.get(w)) {
+                    let fields = field_changes(old, new);
+                    if !fields.is_empty() {
+                        diff.changed.push(IssueChange {
+                            id: id.clone(),
+                            fields,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    let mut added: Vec<String> = (0..target.len())
+        .filter_map(|w| target.node_id(w))
+        .filter(|id| base.node_idx(id).is_none())
+        .collect();
+    added.sort();
+    diff.added = added;
+
+    let base_edges = id_edges(base);
+    let target_edges = id_edges(target);
+    diff.edges_added = target_edges.difference(&base_edges).cloned().collect();
+    diff.edges_removed = base_edges.difference(&target_edges).cloned().collect();
+
+    diff
+}
+
+/// Edge set keyed by IDs so it is independent of index assignment.
+fn id_edges(graph: &DiGraph) -> BTreeSet<(String, String)> {
+    graph
+        .edges()
+        .filter_map(|(u, v)| Some((graph.node_id(u)?, graph.node_id(v)?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(ids: &[&str], edges: &[(usize, usize)]) -> DiGraph {
+        let mut g = DiGraph::new();
+        for id in ids {
+            g.add_node(id);
+        }
+        for &(u, v) in edges {
+            g.add_edge(u, v);
+        }
+        g
+    }
+
+    fn record(title: &str, status: &str) -> IssueRecord {
+        IssueRecord {
+            title: title.to_string(),
+            status: status.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// True if the snapshots are identical at issue level.
+    fn is_empty(diff: &SnapshotDiff) -> bool {
+        diff.added.is_empty()
+            && diff.removed.is_empty()
+            && diff.changed.is_empty()
+            && diff.edges_added.is_empty()
+            && diff.edges_removed.is_empty()
+    }
+
+    #[test]
+    fn test_diff_identical() {
+        let g = graph(&["a", "b"], &[(0, 1)]);
+        let recs = vec![record("A", "open"), record("B", "open")];
+        assert!(is_empty(&snapshot_diff(&g, &recs, &g, &recs)));
+    }
+
+    #[test]
+    fn test_diff_added_removed() {
+        let base = graph(&["a", "b"], &[]);
+        let target = graph(&["c", "a"], &[]);
+        let diff = snapshot_diff(&base, &[], &target, &[]);
+        assert_eq!(diff.added, vec!["c"]);
+        assert_eq!(diff.removed, vec!["b"]);
+        assert!(diff.changed.is_empty());
+    }
+
+    #[test]
+    fn test_diff_field_changes() {
+        let base = graph(&["a", "b"], &[]);
+        let target = graph(&["b", "a"], &[]);
+        let base_recs = vec![record("A", "open"), record("B", "open")];
+        let mut moved = record("B", "closed");
+        moved.labels = vec!["ui".to_string()];
+        moved.updated_at = "2024-02-01".to_string();
+        let target_recs = vec![moved, record("A", "open")];
+
+        let diff = snapshot_diff(&base, &base_recs, &target, &target_recs);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].id, "b");
+        let fields: Vec<&str> = diff.changed[0].fields.iter().map(|f| f.field).collect();
+        assert_eq!(fields, vec!["status", "labels"]);
+        assert_eq!(diff.changed[0].fields[0].new, "closed");
+    }
+
+    #[test]
+    fn test_diff_labels_ignore_order() {
+        let g = graph(&["a"], &[]);
+        let mut base = record("A", "open");
+        base.labels = vec!["ui".to_string(), "auth".to_string()];
+        let mut reordered = base.clone();
+        reordered.labels = vec!["auth".to_string(), "ui".to_string()];
+        assert!(field_changes(&base, &reordered).is_empty());
+
+        reordered.labels.push("db".to_string());
+        let diff = snapshot_diff(&g, &[base], &g, &[reordered]);
+        let change = &diff.changed[0].fields[0];
+        assert_eq!(
+            (change.old.as_str(), change.new.as_str()),
+            ("auth,ui", "auth,db,ui")
+        );
+    }
+
+    #[test]
+    fn test_diff_edges_by_id() {
+        // Same edge a -> b under different indices is not a change
+        let base = graph(&["a", "b", "c"], &[(0, 1)]);
+        let target = graph(&["b", "a", "c"], &[(1, 0), (2, 0)]);
+        let diff = snapshot_diff(&base, &[], &target, &[]);
+        assert_eq!(diff.edges_added, vec![("c".to_string(), "b".to_string())]);
+        assert!(diff.edges_removed.is_empty());
+    }
+}
@@ -0,0 +1,115 @@
+//! Flattened analytic schema: one record per issue with computed metrics.
+//!
+//! Complements the native beads JSONL schema (which the Go side writes) with
+//! the graph metrics the viewer computes, so data pipelines can consume
+//! them without re-running the analysis.
+
+use crate::algorithms::kcore::kcore;
+use crate::algorithms::ranking::{metric_scores, Metric};
+use crate::graph::DiGraph;
+use serde::Serialize;
+
+/// Computed metrics for a single issue.
+#[derive(Debug, Clone, Serialize)]
+pub struct AnalyticRecord {
+    /// Issue ID
+    pub id: String,
+    /// Number of blockers (any status)
+    pub blockers: usize,
+    /// Number of direct dependents
+    pub dependents: usize,
+    /// Open dependents this issue is holding up
+    pub blocks_open: usize,
+    /// PageRank score
+    pub pagerank: f64,
+    /// Betweenness (sampled above 100 nodes, fixed seed)
+    pub betweenness: f64,
+    /// Slack (0 on the critical path; all zeros for cyclic graphs)
+    pub slack: f64,
+    /// Dependency depth (critical path height)
+    pub depth: f64,
+    /// k-core number on the undirected view
+    pub core: u32,
+}
+
+/// Compute analytic records for every node, in node index order.
+///
+/// closed_set only affects `blocks_open`.
+pub fn analytic_records(graph: &DiGraph, closed_set: &[bool]) -> Vec<AnalyticRecord> {
+    let pagerank = metric_scores(graph, Metric::PageRank, closed_set);
+    let betweenness = metric_scores(graph, Metric::Betweenness, closed_set);
+    let slack = metric_scores(graph, Metric::Slack, closed_set);
+    let depth = metric_scores(graph, Metric::Depth, closed_set);
+    let blocks_open = metric_scores(graph, Metric::BlockedCount, closed_set);
+    let core = kcore(graph);
+
+    (0..graph.len())
+        .map(|v| AnalyticRecord {
+            id: graph.node_id(v).unwrap_or_default(),
+            blockers: graph.predecessors_slice(v).len(),
+            dependents: graph.successors_slice(v).len(),
+            blocks_open: blocks_open[v] as usize,
+            pagerank: pagerank[v],
+            betweenness: betweenness[v],
+            slack: slack[v],
+            depth: depth[v],
+            core: core[v],
+        })
+        .collect()
+}
+
+/// Serialize records as JSONL (one JSON object per line, trailing newline).
+pub fn to_jsonl(records: &[AnalyticRecord]) -> String {
+    let mut out = String::new();
+    for record in records {
+        if let Ok(line) = serde_json::to_string(record) {
+            out.push_str(&line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain() -> DiGraph {
+        // a -> b -> c
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph
+    }
+
+    #[test]
+    fn test_analytic_records() {
+        let graph = chain();
+        let records = analytic_records(&graph, &[]);
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].id, "a");
+        assert_eq!(records[0].dependents, 1);
+        assert_eq!(records[1].blockers, 1);
+        assert_eq!(records[2].depth, 3.0);
+        assert!(records.iter().all(|r| r.slack == 0.0));
+
+        let closed = vec![false, true, false];
+        let records = analytic_records(&graph, &closed);
+        assert_eq!(records[0].blocks_open, 0);
+    }
+
+    #[test]
+    fn test_to_jsonl() {
+        let graph = chain();
+        let jsonl = to_jsonl(&analytic_records(&graph, &[]));
+        let lines: Vec<&str> = jsonl.lines().collect();
+        assert_eq!(lines.len(), 3);
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["id"], "a");
+        assert!(first["pagerank"].is_number());
+        assert!(jsonl.ends_with('\n'));
+    }
+}
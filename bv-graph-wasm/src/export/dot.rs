@@ -0,0 +1,209 @@
+//! Graphviz DOT export.
+//!
+//! Nodes are colored by status (closed, blocked, ready) and the edges along
+//! the critical chains of open work are drawn heavier, so the rendered graph
+//! shows at a glance what is done, what is stuck, and what drives the finish
+//! date. Edges point from blocker to blocked issue.
+
+use super::NodeStatus;
+use crate::algorithms::critical_path::critical_path_edges;
+use crate::graph::DiGraph;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+
+const COLOR_CRITICAL_EDGE: &str = "#cc0000";
+
+/// Quote a string as a DOT ID, escaping quotes, backslashes, and newlines.
+fn quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Node statement label: the ID, plus the title on a second line if given.
+fn node_label(id: &str, titles: &[String], node: usize) -> String {
+    match titles.get(node).filter(|t| !t.is_empty()) {
+        Some(title) => quote(&format!("{}\n{}", id, title)),
+        None => quote(id),
+    }
+}
+
+/// Write the graph header shared by plain and clustered output.
+fn write_header(out: &mut String) {
+    out.push_str("digraph dependencies {\n");
+    out.push_str("  rankdir=LR;\n");
+    out.push_str("  node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];\n");
+    out.push_str("  edge [color=\"#666666\"];\n");
+}
+
+/// Write one node statement with status fill color.
+fn write_node(
+    out: &mut String,
+    indent: &str,
+    graph: &DiGraph,
+    node: usize,
+    closed_set: &[bool],
+    titles: &[String],
+) {
+    let Some(id) = graph.node_id(node) else {
+        return;
+    };
+    let _ = writeln!(
+        out,
+        "{}{} [label={}, fillcolor={}];",
+        indent,
+        quote(&id),
+        node_label(&id, titles, node),
+        quote(NodeStatus::of(graph, node, closed_set).color())
+    );
+}
+
+/// Write all edge statements, emphasizing critical-path edges.
+fn write_edges(out: &mut String, graph: &DiGraph, closed_set: &[bool]) {
+    let critical: HashSet<(usize, usize)> =
+        critical_path_edges(graph, closed_set).into_iter().collect();
+    for (u, v) in graph.edges() {
+        let (Some(from), Some(to)) = (graph.node_id(u), graph.node_id(v)) else {
+            continue;
+        };
+        let _ = write!(out, "  {} -> {}", quote(&from), quote(&to));
+        if critical.contains(&(u, v)) {
+            let _ = write!(out, " [color={}, penwidth=2.5]", quote(COLOR_CRITICAL_EDGE));
+        }
+        out.push_str(";\n");
+    }
+}
+
+/// Render the graph as DOT.
+///
+/// # Arguments
+/// * `graph` - The graph to export (usually the filtered subgraph)
+/// * `closed_set` - Boolean array indicating which nodes are closed
+/// * `titles` - Optional per-node titles shown under the ID
+pub fn to_dot(graph: &DiGraph, closed_set: &[bool], titles: &[String]) -> String {
+    let mut out = String::new();
+    write_header(&mut out);
+    for v in 0..graph.len() {
+        write_node(&mut out, "  ", graph, v, closed_set, titles);
+    }
+    write_edges(&mut out, graph, closed_set);
+    out.push_str("}\n");
+    out
+}
+
+/// Render the graph as DOT with one Graphviz cluster per key.
+///
+/// Clusters are emitted in key order; nodes with an empty or missing key
+/// stay outside any cluster. Edges are written after all clusters so
+/// cross-cluster dependencies are drawn between the boxes.
+///
+/// # Arguments
+/// * `graph` - The graph to export (usually the filtered subgraph)
+/// * `closed_set` - Boolean array indicating which nodes are closed
+/// * `titles` - Optional per-node titles shown under the ID
+/// * `clusters` - Cluster key per node (epic, first label, or assignee)
+pub fn to_dot_clustered(
+    graph: &DiGraph,
+    closed_set: &[bool],
+    titles: &[String],
+    clusters: &[String],
+) -> String {
+    let mut groups: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+    let mut loose = Vec::new();
+    for v in 0..graph.len() {
+        match clusters.get(v).filter(|k| !k.is_empty()) {
+            Some(key) => groups.entry(key.as_str()).or_default().push(v),
+            None => loose.push(v),
+        }
+    }
+
+    let mut out = String::new();
+    write_header(&mut out);
+    for (i, (key, nodes)) in groups.iter().enumerate() {
+        let _ = writeln!(out, "  subgraph {} {{", quote(&format!("cluster_{}", i)));
+        let _ = writeln!(out, "    label={};", quote(key));
+        out.push_str("    style=\"rounded\";\n");
+        for &v in nodes {
+            write_node(&mut out, "    ", graph, v, closed_set, titles);
+        }
+        out.push_str("  }\n");
+    }
+    for v in loose {
+        write_node(&mut out, "  ", graph, v, closed_set, titles);
+    }
+    write_edges(&mut out, graph, closed_set);
+    out.push_str("}\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_escapes() {
+        assert_eq!(quote("bv-1"), "\"bv-1\"");
+        assert_eq!(quote("say \"hi\"\\"), "\"say \\\"hi\\\"\\\\\"");
+        assert_eq!(quote("a\nb"), "\"a\\nb\"");
+    }
+
+    #[test]
+    fn test_to_dot_empty() {
+        let dot = to_dot(&DiGraph::new(), &[], &[]);
+        assert!(dot.starts_with("digraph dependencies {"));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_to_dot_nodes_and_edges() {
+        // a -> b -> c, a closed
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        let titles = vec!["First".to_string()];
+
+        let dot = to_dot(&graph, &[true, false, false], &titles);
+        assert!(dot.contains("\"a\" [label=\"a\\nFirst\", fillcolor=\"#d9d9d9\"];"));
+        assert!(dot.contains("\"b\" [label=\"b\", fillcolor=\"#d9ead3\"];"));
+        assert!(dot.contains("\"c\" [label=\"c\", fillcolor=\"#f4cccc\"];"));
+        // a is closed, so only b -> c is on the remaining critical chain
+        assert!(dot.contains("  \"a\" -> \"b\";\n"));
+        assert!(dot.contains("\"b\" -> \"c\" [color=\"#cc0000\", penwidth=2.5];"));
+    }
+
+    #[test]
+    fn test_to_dot_clustered() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_node("d");
+        graph.add_edge(a, c);
+        graph.add_edge(b, c);
+        let clusters = vec!["ui".to_string(), "db".to_string(), "ui".to_string()];
+
+        let dot = to_dot_clustered(&graph, &[], &[], &clusters);
+        // Clusters in key order: db first, then ui
+        let db = dot.find("label=\"db\"").unwrap();
+        let ui = dot.find("label=\"ui\"").unwrap();
+        assert!(db < ui);
+        assert!(dot.contains("  subgraph \"cluster_1\" {\n    label=\"ui\";"));
+        assert!(dot.contains("    \"a\" [label=\"a\""));
+        // d has no key and stays at the top level
+        assert!(dot.contains("\n  \"d\" [label=\"d\""));
+        assert!(dot.contains("\"b\" -> \"c\""));
+    }
+}
@@ -0,0 +1,232 @@
+//! iCalendar (.ics) export of planning milestones.
+//!
+//! Emits one all-day event per issue due date and, when the graph is a
+//! DAG, one per projected finish date of a critical-path issue, so the
+//! dates show up in a team calendar subscribed to the file.
+//!
+//! Projected finishes come from the CPM schedule: a task with earliest
+//! finish `ef` (in days from the project start) is done by the end of day
+//! `ceil(ef)`, counting the start date as day one.
+
+use crate::algorithms::schedule::cpm_schedule;
+use crate::graph::DiGraph;
+
+/// Maximum line length in octets before folding (RFC 5545 §3.1).
+const FOLD_WIDTH: usize = 75;
+
+/// Days since 1970-01-01 for a proleptic Gregorian date.
+pub(crate) fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (m + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Inverse of `days_from_civil`.
+pub(crate) fn civil_from_days(z: i64) -> (i64, i64, i64) {
+    let z = z + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400;
+    (if m <= 2 { y + 1 } else { y }, m, d)
+}
+
+/// Parse the `YYYY-MM-DD` prefix of a date or ISO-8601 timestamp.
+pub(crate) fn parse_date(s: &str) -> Option<i64> {
+    let date = s.get(..10)?;
+    let mut parts = date.split('-');
+    let y: i64 = parts.next()?.parse().ok()?;
+    let m: i64 = parts.next()?.parse().ok()?;
+    let d: i64 = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&m) || !(1..=31).contains(&d) {
+        return None;
+    }
+    let days = days_from_civil(y, m, d);
+    // Reject dates like 2023-02-30 that roll over into the next month
+    (civil_from_days(days) == (y, m, d)).then_some(days)
+}
+
+/// Format days since the epoch as an iCalendar DATE (`YYYYMMDD`).
+fn format_date(days: i64) -> String {
+    let (y, m, d) = civil_from_days(days);
+    format!("{:04}{:02}{:02}", y, m, d)
+}
+
+/// Escape TEXT values (RFC 5545 §3.3.11).
+fn escape_text(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Append a content line, folding it at FOLD_WIDTH octets without
+/// splitting a UTF-8 character.
+fn push_line(out: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > FOLD_WIDTH {
+            out.push_str("\r\n ");
+            // The leading space counts toward the continuation line
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+/// Append an all-day VEVENT.
+fn push_event(out: &mut String, uid: &str, stamp: &str, day: i64, summary: &str) {
+    push_line(out, "BEGIN:VEVENT");
+    push_line(out, &format!("UID:{}", uid));
+    push_line(out, &format!("DTSTAMP:{}", stamp));
+    push_line(out, &format!("DTSTART;VALUE=DATE:{}", format_date(day)));
+    push_line(out, &format!("DTEND;VALUE=DATE:{}", format_date(day + 1)));
+    push_line(out, &format!("SUMMARY:{}", escape_text(summary)));
+    push_line(out, "END:VEVENT");
+}
+
+/// Render due dates and projected critical-path finishes as an .ics calendar.
+///
+/// Nodes with an empty or unparsable due date get no due event. Projected
+/// finishes need a valid `start_date` and an acyclic graph; otherwise only
+/// due dates are emitted. `DTSTAMP` is the start date (or the Unix epoch)
+/// so the output is deterministic and re-exports do not churn.
+///
+/// # Arguments
+/// * `graph` - The dependency graph (usually open work)
+/// * `durations` - Per-node durations in days (missing entries use the CPM default)
+/// * `titles` - Optional per-node titles used in event summaries
+/// * `due_dates` - Per-node due dates (YYYY-MM-DD or ISO-8601)
+/// * `start_date` - Project start date (YYYY-MM-DD)
+pub fn to_ical(
+    graph: &DiGraph,
+    durations: &[f64],
+    titles: &[String],
+    due_dates: &[String],
+    start_date: &str,
+) -> String {
+    let start = parse_date(start_date);
+    let stamp = format!("{}T000000Z", format_date(start.unwrap_or(0)));
+    let summary = |prefix: &str, v: usize, id: &str| match titles.get(v).filter(|t| !t.is_empty()) {
+        Some(title) => format!("{}: {} {}", prefix, id, title),
+        None => format!("{}: {}", prefix, id),
+    };
+
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, "PRODID:-//b9s//dependency graph//EN");
+    push_line(&mut out, "CALSCALE:GREGORIAN");
+
+    for v in 0..graph.len() {
+        let Some(id) = graph.node_id(v) else {
+            continue;
+        };
+        if let Some(day) = due_dates.get(v).and_then(|d| parse_date(d)) {
+            let uid = format!("due-{}@b9s", id);
+            push_event(&mut out, &uid, &stamp, day, &summary("Due", v, &id));
+        }
+    }
+
+    if let Some(start) = start {
+        for entry in cpm_schedule(graph, durations).entries {
+            let Some(id) = graph.node_id(entry.node) else {
+                continue;
+            };
+            if !entry.critical {
+                continue;
+            }
+            let day = start + (entry.earliest_finish.ceil() as i64).max(1) - 1;
+            let uid = format!("finish-{}@b9s", id);
+            let text = summary("Projected finish", entry.node, &id);
+            push_event(&mut out, &uid, &stamp, day, &text);
+        }
+    }
+
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_round_trip() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(parse_date("2024-03-01"), Some(days_from_civil(2024, 3, 1)));
+        assert_eq!(format_date(days_from_civil(2024, 2, 28) + 1), "20240229");
+        assert_eq!(parse_date("2023-02-29"), None);
+        assert_eq!(parse_date("2024-01-05T10:00:00Z"), parse_date("2024-01-05"));
+        assert_eq!(parse_date("soon"), None);
+    }
+
+    #[test]
+    fn test_escape_and_fold() {
+        assert_eq!(escape_text("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+        let mut out = String::new();
+        push_line(&mut out, &"x".repeat(80));
+        let lines: Vec<&str> = out.split("\r\n").collect();
+        assert_eq!(lines[0].len(), 75);
+        assert_eq!(lines[1], format!(" {}", "x".repeat(5)));
+    }
+
+    #[test]
+    fn test_to_ical_events() {
+        // a (2d) -> b (1d) is critical; c (1d) has slack
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_node("c");
+        graph.add_edge(a, b);
+        let titles = vec!["Design, review".to_string()];
+        let due = vec![String::new(), "2024-01-10".to_string()];
+
+        let ics = to_ical(&graph, &[2.0, 1.0, 1.0], &titles, &due, "2024-01-01");
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains("UID:due-b@b9s\r\nDTSTAMP:20240101T000000Z\r\n"));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20240110\r\nDTEND;VALUE=DATE:20240111\r\n"));
+        // a occupies Jan 1-2, b finishes on Jan 3
+        assert!(ics.contains("DTSTART;VALUE=DATE:20240102\r\n"));
+        assert!(ics.contains("SUMMARY:Projected finish: a Design\\, review\r\n"));
+        assert!(ics.contains(
+            "UID:finish-b@b9s\r\nDTSTAMP:20240101T000000Z\r\nDTSTART;VALUE=DATE:20240103"
+        ));
+        assert!(!ics.contains("finish-c@b9s"));
+    }
+
+    #[test]
+    fn test_to_ical_without_schedule() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+        let due = vec!["2024-01-10".to_string()];
+        let ics = to_ical(&graph, &[], &[], &due, "2024-01-01");
+        assert!(ics.contains("UID:due-a@b9s"));
+        assert!(!ics.contains("Projected finish"));
+        // Empty graph: calendar shell only
+        let ics = to_ical(&DiGraph::new(), &[], &[], &[], "");
+        assert!(!ics.contains("BEGIN:VEVENT"));
+    }
+}
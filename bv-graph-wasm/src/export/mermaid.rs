@@ -0,0 +1,233 @@
+//! Mermaid export for pasting diagrams into GitHub/GitLab markdown.
+//!
+//! Two diagram kinds:
+//! - `graph TD` flowchart of the (sub)graph, colored by status with the
+//!   critical chains of open work drawn heavier,
+//! - `gantt` chart of the CPM schedule, where each task starts `after`
+//!   its blockers so Mermaid reproduces the computed timeline.
+//!
+//! Issue IDs are not valid Mermaid identifiers, so nodes and tasks are
+//! named `n<index>` and the ID is shown in the label.
+
+use super::NodeStatus;
+use crate::algorithms::critical_path::critical_path_edges;
+use crate::algorithms::schedule::cpm_schedule;
+use crate::graph::DiGraph;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// Line style for critical-path edges (Mermaid `linkStyle`).
+const CRITICAL_LINK_STYLE: &str = "stroke:#cc0000,stroke-width:3px";
+
+/// Escape text for a quoted flowchart label.
+fn flowchart_label(text: &str) -> String {
+    text.replace('"', "#quot;").replace('\n', "<br/>")
+}
+
+/// Strip characters that end a gantt task title (`:`), start a comment (`#`),
+/// or break the line.
+fn gantt_title(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            ':' | ';' | '#' | '\n' | '\r' => ' ',
+            c => c,
+        })
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+/// Format a duration in days, falling back to hours for fractions.
+fn gantt_duration(days: f64) -> String {
+    if days.fract() == 0.0 {
+        format!("{}d", days as u64)
+    } else {
+        format!("{}h", (days * 24.0).round() as u64)
+    }
+}
+
+/// Text shown for a node: the ID, plus the title if given.
+fn display_text(id: &str, titles: &[String], node: usize) -> String {
+    match titles.get(node).filter(|t| !t.is_empty()) {
+        Some(title) => format!("{} {}", id, title),
+        None => id.to_string(),
+    }
+}
+
+/// Render the graph as a Mermaid `graph TD` flowchart.
+///
+/// # Arguments
+/// * `graph` - The graph to export (usually the selected subgraph)
+/// * `closed_set` - Boolean array indicating which nodes are closed
+/// * `titles` - Optional per-node titles shown after the ID
+pub fn to_mermaid_flowchart(graph: &DiGraph, closed_set: &[bool], titles: &[String]) -> String {
+    let mut out = String::from("graph TD\n");
+    for status in [NodeStatus::Closed, NodeStatus::Blocked, NodeStatus::Ready] {
+        let _ = writeln!(
+            out,
+            "  classDef {} fill:{}",
+            class_name(status),
+            status.color()
+        );
+    }
+
+    for v in 0..graph.len() {
+        let Some(id) = graph.node_id(v) else {
+            continue;
+        };
+        let _ = writeln!(
+            out,
+            "  n{}[\"{}\"]:::{}",
+            v,
+            flowchart_label(&display_text(&id, titles, v)),
+            class_name(NodeStatus::of(graph, v, closed_set))
+        );
+    }
+
+    let critical: HashSet<(usize, usize)> =
+        critical_path_edges(graph, closed_set).into_iter().collect();
+    let mut critical_links = Vec::new();
+    for (i, (u, v)) in graph.edges().enumerate() {
+        let _ = writeln!(out, "  n{} --> n{}", u, v);
+        if critical.contains(&(u, v)) {
+            critical_links.push(i.to_string());
+        }
+    }
+    if !critical_links.is_empty() {
+        let _ = writeln!(
+            out,
+            "  linkStyle {} {}",
+            critical_links.join(","),
+            CRITICAL_LINK_STYLE
+        );
+    }
+    out
+}
+
+fn class_name(status: NodeStatus) -> &'static str {
+    match status {
+        NodeStatus::Closed => "closed",
+        NodeStatus::Blocked => "blocked",
+        NodeStatus::Ready => "ready",
+    }
+}
+
+/// Render the CPM schedule as a Mermaid `gantt` chart.
+///
+/// Durations are taken as days. Root tasks start on `start_date`
+/// (YYYY-MM-DD); every other task starts after all its blockers, which
+/// matches the CPM earliest start. Critical tasks are tagged `crit` and
+/// zero-length tasks become milestones. Cyclic graphs have no schedule, so
+/// only the header and a comment are emitted.
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `durations` - Per-node durations in days (missing entries use the CPM default)
+/// * `titles` - Optional per-node titles shown after the ID
+/// * `start_date` - Project start date (YYYY-MM-DD)
+pub fn to_mermaid_gantt(
+    graph: &DiGraph,
+    durations: &[f64],
+    titles: &[String],
+    start_date: &str,
+) -> String {
+    let mut out = String::from("gantt\n  dateFormat YYYY-MM-DD\n");
+    let schedule = cpm_schedule(graph, durations);
+    if !schedule.is_dag {
+        out.push_str("  %% dependency cycle: no schedule\n");
+        return out;
+    }
+
+    let mut order: Vec<usize> = (0..graph.len()).collect();
+    order.sort_by(|&a, &b| {
+        schedule.entries[a]
+            .earliest_start
+            .partial_cmp(&schedule.entries[b].earliest_start)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.cmp(&b))
+    });
+
+    for v in order {
+        let entry = &schedule.entries[v];
+        let id = graph.node_id(v).unwrap_or_default();
+        let mut tags = Vec::new();
+        if entry.critical {
+            tags.push("crit".to_string());
+        }
+        if entry.duration == 0.0 {
+            tags.push("milestone".to_string());
+        }
+        tags.push(format!("n{}", v));
+
+        let blockers = graph.predecessors_slice(v);
+        if blockers.is_empty() {
+            tags.push(start_date.to_string());
+        } else {
+            let after: Vec<String> = blockers.iter().map(|u| format!("n{}", u)).collect();
+            tags.push(format!("after {}", after.join(" ")));
+        }
+        tags.push(gantt_duration(entry.duration));
+
+        let _ = writeln!(
+            out,
+            "  {} :{}",
+            gantt_title(&display_text(&id, titles, v)),
+            tags.join(", ")
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain() -> DiGraph {
+        // a -> b -> c, d isolated
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph
+    }
+
+    #[test]
+    fn test_flowchart() {
+        let graph = chain();
+        let titles = vec!["Say \"hi\"".to_string()];
+        let out = to_mermaid_flowchart(&graph, &[], &titles);
+        assert!(out.starts_with("graph TD\n"));
+        assert!(out.contains("  n0[\"a Say #quot;hi#quot;\"]:::ready\n"));
+        assert!(out.contains("  n1[\"b\"]:::blocked\n"));
+        assert!(out.contains("  n0 --> n1\n"));
+        assert!(out.contains("  linkStyle 0,1 stroke:#cc0000,stroke-width:3px\n"));
+    }
+
+    #[test]
+    fn test_gantt() {
+        let graph = chain();
+        let out = to_mermaid_gantt(&graph, &[2.0, 0.5, 0.0, 1.0], &[], "2024-01-01");
+        assert!(out.contains("  a :crit, n0, 2024-01-01, 2d\n"));
+        assert!(out.contains("  b :crit, n1, after n0, 12h\n"));
+        assert!(out.contains("  c :crit, milestone, n2, after n1, 0d\n"));
+        assert!(out.contains("  d :n3, 2024-01-01, 1d\n"));
+        // Tasks are listed by earliest start, so blockers come first
+        assert!(out.find("  a :").unwrap() < out.find("  b :").unwrap());
+    }
+
+    #[test]
+    fn test_gantt_cycle_and_titles() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+        let out = to_mermaid_gantt(&graph, &[], &[], "2024-01-01");
+        assert!(out.contains("%% dependency cycle"));
+
+        assert_eq!(gantt_title("bv-1: fix #3"), "bv-1  fix  3");
+    }
+}
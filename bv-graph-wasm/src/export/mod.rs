@@ -0,0 +1,45 @@
+//! Exporters for sharing the dependency graph outside the viewer.
+//!
+//! Each exporter renders a graph (typically a filtered subgraph) into a
+//! text format for downstream tools: flattened analytic records for data
+//! pipelines, diagram sources and status reports for docs, and calendar
+//! and outline files for planning tools.
+
+pub mod analytic;
+pub mod dot;
+pub mod ical;
+pub mod mermaid;
+pub mod org;
+pub mod report;
+
+use crate::graph::DiGraph;
+use crate::reachability::open_blocker_count;
+
+/// Display status of a node, shared by the diagram exporters for coloring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NodeStatus {
+    Closed,
+    Blocked,
+    Ready,
+}
+
+impl NodeStatus {
+    pub(crate) fn of(graph: &DiGraph, node: usize, closed_set: &[bool]) -> NodeStatus {
+        if closed_set.get(node).copied().unwrap_or(false) {
+            NodeStatus::Closed
+        } else if open_blocker_count(graph, node, closed_set) > 0 {
+            NodeStatus::Blocked
+        } else {
+            NodeStatus::Ready
+        }
+    }
+
+    /// Fill color used for this status in rendered diagrams.
+    pub(crate) fn color(self) -> &'static str {
+        match self {
+            NodeStatus::Closed => "#d9d9d9",
+            NodeStatus::Blocked => "#f4cccc",
+            NodeStatus::Ready => "#d9ead3",
+        }
+    }
+}
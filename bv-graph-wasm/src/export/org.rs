@@ -0,0 +1,116 @@
+//! Org-mode outline export.
+//!
+//! Issues become TODO/DONE headings nested by the parent-child hierarchy,
+//! so epics are headings over their children. Parents carry an Org
+//! statistics cookie (`[closed/total]` over all descendants) and every
+//! entry gets a property drawer with its ID, estimate, and blockers.
+
+use crate::graph::DiGraph;
+use crate::hierarchy::hierarchy_rollup;
+use std::fmt::Write;
+
+/// Heading text: the title, falling back to the ID. Newlines would end the
+/// heading, so they are folded into spaces.
+fn heading_text(id: &str, titles: &[String], node: usize) -> String {
+    match titles.get(node).filter(|t| !t.is_empty()) {
+        Some(title) => title.replace(['\n', '\r'], " "),
+        None => id.to_string(),
+    }
+}
+
+/// Render the issue hierarchy as an Org-mode outline.
+///
+/// Headings follow the hierarchy pre-order, so each epic is followed by its
+/// subtree. `:BLOCKED_BY:` lists every direct blocker, open or closed.
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `parents` - Parent index per node (None for top-level nodes)
+/// * `closed_set` - Boolean array indicating which nodes are closed
+/// * `titles` - Optional per-node titles (the ID is used when missing)
+/// * `estimates` - Per-node estimates (missing or zero entries are omitted)
+pub fn to_org(
+    graph: &DiGraph,
+    parents: &[Option<usize>],
+    closed_set: &[bool],
+    titles: &[String],
+    estimates: &[f64],
+) -> String {
+    let tree = hierarchy_rollup(graph, parents, closed_set, estimates);
+    let mut out = String::new();
+    for &v in &tree.order {
+        let Some(id) = graph.node_id(v) else {
+            continue;
+        };
+        let entry = &tree.nodes[v];
+        let keyword = if closed_set.get(v).copied().unwrap_or(false) {
+            "DONE"
+        } else {
+            "TODO"
+        };
+        let _ = write!(
+            out,
+            "{} {} {}",
+            "*".repeat(entry.depth + 1),
+            keyword,
+            heading_text(&id, titles, v)
+        );
+        if !entry.children.is_empty() {
+            let total = entry.descendants_open + entry.descendants_closed;
+            let _ = write!(out, " [{}/{}]", entry.descendants_closed, total);
+        }
+        out.push('\n');
+
+        out.push_str(":PROPERTIES:\n");
+        let _ = writeln!(out, ":ID: {}", id);
+        if let Some(e) = estimates.get(v).filter(|e| e.is_finite() && **e > 0.0) {
+            let _ = writeln!(out, ":ESTIMATE: {}", e);
+        }
+        let blockers: Vec<String> = graph
+            .predecessors_slice(v)
+            .iter()
+            .filter_map(|&u| graph.node_id(u))
+            .collect();
+        if !blockers.is_empty() {
+            let _ = writeln!(out, ":BLOCKED_BY: {}", blockers.join(" "));
+        }
+        out.push_str(":END:\n");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_org_empty() {
+        assert_eq!(to_org(&DiGraph::new(), &[], &[], &[], &[]), "");
+    }
+
+    #[test]
+    fn test_to_org_outline() {
+        // epic with children a (closed) and b; a blocks b
+        let mut graph = DiGraph::new();
+        let epic = graph.add_node("bv-1");
+        let a = graph.add_node("bv-2");
+        let b = graph.add_node("bv-3");
+        graph.add_edge(a, b);
+        let parents = vec![None, Some(epic), Some(epic)];
+        let titles = vec!["Launch".to_string(), "Design".to_string()];
+
+        let org = to_org(
+            &graph,
+            &parents,
+            &[false, true, false],
+            &titles,
+            &[0.0, 2.0, 1.5],
+        );
+        assert_eq!(
+            org,
+            "* TODO Launch [1/2]\n:PROPERTIES:\n:ID: bv-1\n:END:\n\
+             ** DONE Design\n:PROPERTIES:\n:ID: bv-2\n:ESTIMATE: 2\n:END:\n\
+             ** TODO bv-3\n:PROPERTIES:\n:ID: bv-3\n:ESTIMATE: 1.5\n:BLOCKED_BY: bv-2\n:END:\n"
+        );
+    }
+}
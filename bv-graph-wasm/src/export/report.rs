@@ -0,0 +1,233 @@
+//! Markdown status report for weekly updates.
+//!
+//! Summarizes activity since a cutoff date (closed and opened issues,
+//! newly blocked work) next to the current shape of the graph (critical
+//! path and top risks by betweenness over open work, dependency cycles), as
+//! markdown ready to paste into a status update.
+//!
+//! Timestamps are ISO-8601 strings compared lexicographically, so a bare
+//! date such as `2024-01-01` works as the cutoff.
+
+use crate::algorithms::cycles::tarjan_scc;
+use crate::algorithms::ranking::{metric_scores, top_n, Metric};
+use crate::algorithms::schedule::cpm_schedule;
+use crate::algorithms::subgraph::extract_subgraph;
+use crate::filter::IssueRecord;
+use crate::graph::DiGraph;
+use crate::reachability::open_blockers;
+use std::fmt::Write;
+
+/// Format an issue as a list item: `- `id` title`.
+fn item(graph: &DiGraph, records: &[IssueRecord], node: usize) -> String {
+    let id = graph.node_id(node).unwrap_or_default();
+    match records
+        .get(node)
+        .map(|r| r.title.as_str())
+        .filter(|t| !t.is_empty())
+    {
+        Some(title) => format!("- `{}` {}\n", id, title),
+        None => format!("- `{}`\n", id),
+    }
+}
+
+/// Write a section with one list item per node, or "_None._" if empty.
+fn section(
+    out: &mut String,
+    heading: &str,
+    graph: &DiGraph,
+    records: &[IssueRecord],
+    nodes: &[usize],
+) {
+    let _ = writeln!(out, "## {} ({})\n", heading, nodes.len());
+    if nodes.is_empty() {
+        out.push_str("_None._\n");
+    }
+    for &v in nodes {
+        out.push_str(&item(graph, records, v));
+    }
+    out.push('\n');
+}
+
+/// Generate the markdown report.
+///
+/// Closing time is `closed_at`, falling back to `updated_at` for records
+/// that do not carry it. An issue counts as newly blocked if it is open,
+/// has open blockers, and either it or one of those blockers was opened
+/// since the cutoff.
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `records` - Issue records parallel to node indices (status drives closed state)
+/// * `since` - Cutoff timestamp (inclusive)
+/// * `limit` - Maximum number of top risks to list
+pub fn markdown_report(
+    graph: &DiGraph,
+    records: &[IssueRecord],
+    since: &str,
+    limit: usize,
+) -> String {
+    let n = graph.len();
+    let closed: Vec<bool> = (0..n)
+        .map(|v| records.get(v).is_some_and(IssueRecord::is_closed))
+        .collect();
+    let created_since = |v: usize| {
+        records
+            .get(v)
+            .is_some_and(|r| !r.created_at.is_empty() && r.created_at.as_str() >= since)
+    };
+
+    let closed_since: Vec<usize> = (0..n)
+        .filter(|&v| closed[v])
+        .filter(|&v| {
+            let r = &records[v];
+            let at = if r.closed_at.is_empty() {
+                &r.updated_at
+            } else {
+                &r.closed_at
+            };
+            !at.is_empty() && at.as_str() >= since
+        })
+        .collect();
+    let opened_since: Vec<usize> = (0..n).filter(|&v| created_since(v)).collect();
+
+    let open: Vec<usize> = (0..n).filter(|&v| !closed[v]).collect();
+    let mut blocked = 0;
+    let mut newly_blocked = Vec::new();
+    for &v in &open {
+        let blockers = open_blockers(graph, v, &closed);
+        if blockers.is_empty() {
+            continue;
+        }
+        blocked += 1;
+        if created_since(v) || blockers.iter().any(|&b| created_since(b)) {
+            newly_blocked.push(v);
+        }
+    }
+
+    let mut out = String::new();
+    let _ = writeln!(out, "# Status report since {}\n", since);
+    let _ = writeln!(
+        out,
+        "**{}** open · **{}** blocked · **{}** ready · **{}** closed and **{}** opened since {}\n",
+        open.len(),
+        blocked,
+        open.len() - blocked,
+        closed_since.len(),
+        opened_since.len(),
+        since
+    );
+
+    section(&mut out, "Closed", graph, records, &closed_since);
+    section(&mut out, "Opened", graph, records, &opened_since);
+    section(&mut out, "Newly blocked", graph, records, &newly_blocked);
+
+    // Critical path and risks over open work; extract_subgraph renumbers in input order
+    let sub = extract_subgraph(graph, &open);
+    let schedule = cpm_schedule(&sub, &[]);
+    let chain: Vec<usize> = schedule.critical_path.iter().map(|&i| open[i]).collect();
+    if schedule.is_dag {
+        section(&mut out, "Critical path", graph, records, &chain);
+    } else {
+        out.push_str(
+            "## Critical path\n\n_Not available: open work contains a dependency cycle._\n\n",
+        );
+    }
+
+    let scores = metric_scores(&sub, Metric::Betweenness, &[]);
+    let risks: Vec<usize> = top_n(&sub, &scores, limit, true)
+        .into_iter()
+        .filter(|r| r.score > 0.0)
+        .map(|r| open[r.node])
+        .collect();
+    section(&mut out, "Top risks by betweenness", graph, records, &risks);
+
+    let cycles: Vec<Vec<usize>> = tarjan_scc(graph)
+        .components
+        .into_iter()
+        .filter(|c| c.len() > 1)
+        .collect();
+    let _ = writeln!(out, "## Cycle warnings ({})\n", cycles.len());
+    if cycles.is_empty() {
+        out.push_str("_None._\n");
+    }
+    for mut cycle in cycles {
+        cycle.sort_unstable();
+        let ids: Vec<String> = cycle
+            .iter()
+            .map(|&v| format!("`{}`", graph.node_id(v).unwrap_or_default()))
+            .collect();
+        let _ = writeln!(out, "- {}", ids.join(", "));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(title: &str, status: &str, created: &str, closed: &str) -> IssueRecord {
+        IssueRecord {
+            title: title.to_string(),
+            status: status.to_string(),
+            created_at: created.to_string(),
+            closed_at: closed.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// old(closed) -> mid -> end, new -> end
+    fn fixture() -> (DiGraph, Vec<IssueRecord>) {
+        let mut graph = DiGraph::new();
+        let old = graph.add_node("bv-1");
+        let mid = graph.add_node("bv-2");
+        let end = graph.add_node("bv-3");
+        let new = graph.add_node("bv-4");
+        graph.add_edge(old, mid);
+        graph.add_edge(mid, end);
+        graph.add_edge(new, end);
+        let records = vec![
+            record("Old", "closed", "2023-11-01", "2024-01-05"),
+            record("Mid", "open", "2023-11-02", ""),
+            record("End", "open", "2023-11-03", ""),
+            record("New", "open", "2024-01-02", ""),
+        ];
+        (graph, records)
+    }
+
+    #[test]
+    fn test_report_activity_sections() {
+        let (graph, records) = fixture();
+        let report = markdown_report(&graph, &records, "2024-01-01", 5);
+        assert!(report.starts_with("# Status report since 2024-01-01\n"));
+        assert!(report.contains("**3** open · **1** blocked · **2** ready"));
+        assert!(report.contains("## Closed (1)\n\n- `bv-1` Old\n"));
+        assert!(report.contains("## Opened (1)\n\n- `bv-4` New\n"));
+        // bv-3 is blocked by the newly opened bv-4
+        assert!(report.contains("## Newly blocked (1)\n\n- `bv-3` End\n"));
+        assert!(report.contains("## Cycle warnings (0)\n\n_None._\n"));
+    }
+
+    #[test]
+    fn test_report_critical_path_and_risks() {
+        let (graph, records) = fixture();
+        let report = markdown_report(&graph, &records, "2024-01-01", 5);
+        assert!(report.contains("## Critical path (2)\n\n- `bv-2` Mid\n- `bv-3` End\n"));
+        // No open node sits between two others, so nothing is a broker
+        assert!(report.contains("## Top risks by betweenness (0)"));
+        // Nothing happened after the cutoff
+        let later = markdown_report(&graph, &records, "2025-01-01", 5);
+        assert!(later.contains("## Closed (0)\n\n_None._\n"));
+    }
+
+    #[test]
+    fn test_report_cycle_warning() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+        let report = markdown_report(&graph, &[], "2024-01-01", 5);
+        assert!(report.contains("_Not available: open work contains a dependency cycle._"));
+        assert!(report.contains("## Cycle warnings (1)\n\n- `a`, `b`\n"));
+    }
+}
@@ -0,0 +1,496 @@
+//! User-defined computed metrics.
+//!
+//! Evaluates small arithmetic expressions such as
+//! `pagerank * blocked_days + 2 * is_critical_path` once per node, so a
+//! config can define custom columns that sort and filter like built-in
+//! metrics.
+//!
+//! Grammar, loosest binding first:
+//! - comparisons `<`, `<=`, `>`, `>=`, `==`, `!=` (1 when true, 0 when false),
+//! - `+`, `-`,
+//! - `*`, `/`,
+//! - unary `-`,
+//! - numbers, variables, `( ... )`, and calls to `min`, `max`, `abs`.
+//!
+//! Variables are either built-in graph values (see [`BUILTINS`]) or
+//! per-node values supplied by the caller (e.g. `blocked_days` or
+//! `estimate`); caller-supplied values shadow built-ins of the same name.
+//! Names are case-insensitive.
+
+use crate::algorithms::critical_path::critical_path_members;
+use crate::algorithms::ranking::{metric_scores, Metric};
+use crate::graph::DiGraph;
+use crate::reachability::open_blocker_count;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Built-in variables computed from the graph.
+pub const BUILTINS: &[&str] = &[
+    "pagerank",
+    "betweenness",
+    "slack",
+    "blocked_count",
+    "depth",
+    "in_degree",
+    "out_degree",
+    "is_critical_path",
+    "is_closed",
+    "is_blocked",
+];
+
+/// Functions callable from an expression, with their arity.
+const FUNCTIONS: &[(&str, usize)] = &[("min", 2), ("max", 2), ("abs", 1)];
+
+/// Error produced while parsing or resolving an expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExprError {
+    /// Human-readable description
+    pub message: String,
+    /// Byte offset of the offending token in the expression
+    pub position: usize,
+}
+
+impl fmt::Display for ExprError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at {})", self.message, self.position)
+    }
+}
+
+/// Binary operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl BinOp {
+    fn apply(self, a: f64, b: f64) -> f64 {
+        let truth = |t: bool| if t { 1.0 } else { 0.0 };
+        match self {
+            BinOp::Add => a + b,
+            BinOp::Sub => a - b,
+            BinOp::Mul => a * b,
+            BinOp::Div => a / b,
+            BinOp::Lt => truth(a < b),
+            BinOp::Le => truth(a <= b),
+            BinOp::Gt => truth(a > b),
+            BinOp::Ge => truth(a >= b),
+            BinOp::Eq => truth(a == b),
+            BinOp::Ne => truth(a != b),
+        }
+    }
+}
+
+/// Parsed expression tree.
+#[derive(Debug, Clone)]
+enum Node {
+    Num(f64),
+    Var(String, usize),
+    Neg(Box<Node>),
+    Binary(BinOp, Box<Node>, Box<Node>),
+    Call(String, Vec<Node>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Num(f64),
+    Ident(String),
+    Op(&'static str),
+    LParen,
+    RParen,
+    Comma,
+}
+
+/// Split an expression into (offset, token) pairs.
+fn tokenize(src: &str) -> Result<Vec<(usize, Token)>, ExprError> {
+    const OPS: &[&str] = &["<=", ">=", "==", "!=", "<", ">", "+", "-", "*", "/"];
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < src.len() {
+        let rest = &src[i..];
+        let c = rest.chars().next().unwrap_or(' ');
+        if c.is_whitespace() {
+            i += c.len_utf8();
+            continue;
+        }
+        let (token, len) = if c.is_ascii_digit() || c == '.' {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                .unwrap_or(rest.len());
+            let value = rest[..len].parse().map_err(|_| ExprError {
+                message: format!("invalid number '{}'", &rest[..len]),
+                position: i,
+            })?;
+            (Token::Num(value), len)
+        } else if c.is_alphabetic() || c == '_' {
+            let len = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            (Token::Ident(rest[..len].to_ascii_lowercase()), len)
+        } else if c == '(' {
+            (Token::LParen, 1)
+        } else if c == ')' {
+            (Token::RParen, 1)
+        } else if c == ',' {
+            (Token::Comma, 1)
+        } else if let Some(op) = OPS.iter().find(|op| rest.starts_with(**op)) {
+            (Token::Op(op), op.len())
+        } else {
+            return Err(ExprError {
+                message: format!("unexpected character '{}'", c),
+                position: i,
+            });
+        };
+        tokens.push((i, token));
+        i += len;
+    }
+    Ok(tokens)
+}
+
+/// Recursive-descent parser over the token list.
+struct Parser<'a> {
+    tokens: &'a [(usize, Token)],
+    pos: usize,
+    end: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(_, t)| t)
+    }
+
+    fn offset(&self) -> usize {
+        self.tokens.get(self.pos).map_or(self.end, |(o, _)| *o)
+    }
+
+    fn err(&self, message: String) -> ExprError {
+        ExprError {
+            message,
+            position: self.offset(),
+        }
+    }
+
+    /// Consume the next token if it is one of the given operators.
+    fn take_op(&mut self, ops: &[(&str, BinOp)]) -> Option<BinOp> {
+        let Some(Token::Op(s)) = self.peek() else {
+            return None;
+        };
+        let op = ops.iter().find(|(o, _)| o == s).map(|&(_, op)| op)?;
+        self.pos += 1;
+        Some(op)
+    }
+
+    fn binary(
+        &mut self,
+        ops: &[(&str, BinOp)],
+        next: fn(&mut Self) -> Result<Node, ExprError>,
+    ) -> Result<Node, ExprError> {
+        let mut lhs = next(self)?;
+        while let Some(op) = self.take_op(ops) {
+            let rhs = next(self)?;
+            lhs = Node::Binary(op, Box::new(lhs), Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn comparison(&mut self) -> Result<Node, ExprError> {
+        const OPS: &[(&str, BinOp)] = &[
+            ("<", BinOp::Lt),
+            ("<=", BinOp::Le),
+            (">", BinOp::Gt),
+            (">=", BinOp::Ge),
+            ("==", BinOp::Eq),
+            ("!=", BinOp::Ne),
+        ];
+        self.binary(OPS, Self::additive)
+    }
+
+    fn additive(&mut self) -> Result<Node, ExprError> {
+        self.binary(
+            &[("+", BinOp::Add), ("-", BinOp::Sub)],
+            Self::multiplicative,
+        )
+    }
+
+    fn multiplicative(&mut self) -> Result<Node, ExprError> {
+        self.binary(&[("*", BinOp::Mul), ("/", BinOp::Div)], Self::unary)
+    }
+
+    fn unary(&mut self) -> Result<Node, ExprError> {
+        if self.peek() == Some(&Token::Op("-")) {
+            self.pos += 1;
+            return Ok(Node::Neg(Box::new(self.unary()?)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Node, ExprError> {
+        let offset = self.offset();
+        let Some(token) = self.peek().cloned() else {
+            return Err(self.err("unexpected end of expression".to_string()));
+        };
+        self.pos += 1;
+        match token {
+            Token::Num(v) => Ok(Node::Num(v)),
+            Token::LParen => {
+                let inner = self.comparison()?;
+                self.expect(Token::RParen, "')'")?;
+                Ok(inner)
+            }
+            Token::Ident(name) if self.peek() == Some(&Token::LParen) => {
+                self.pos += 1;
+                let &(_, arity) = FUNCTIONS
+                    .iter()
+                    .find(|(f, _)| *f == name)
+                    .ok_or(ExprError {
+                        message: format!("unknown function '{}'", name),
+                        position: offset,
+                    })?;
+                let mut args = vec![self.comparison()?];
+                while self.peek() == Some(&Token::Comma) {
+                    self.pos += 1;
+                    args.push(self.comparison()?);
+                }
+                self.expect(Token::RParen, "')'")?;
+                if args.len() != arity {
+                    return Err(ExprError {
+                        message: format!("'{}' takes {} argument(s)", name, arity),
+                        position: offset,
+                    });
+                }
+                Ok(Node::Call(name, args))
+            }
+            Token::Ident(name) => Ok(Node::Var(name, offset)),
+            other => {
+                self.pos -= 1;
+                Err(self.err(format!("unexpected {:?}", other)))
+            }
+        }
+    }
+
+    fn expect(&mut self, token: Token, what: &str) -> Result<(), ExprError> {
+        if self.peek() == Some(&token) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.err(format!("expected {}", what)))
+        }
+    }
+}
+
+/// A parsed, reusable metric expression.
+#[derive(Debug, Clone)]
+pub struct Expr {
+    root: Node,
+}
+
+impl Expr {
+    /// Parse an expression.
+    pub fn parse(src: &str) -> Result<Expr, ExprError> {
+        let tokens = tokenize(src)?;
+        let mut parser = Parser {
+            tokens: &tokens,
+            pos: 0,
+            end: src.len(),
+        };
+        let root = parser.comparison()?;
+        if parser.pos < tokens.len() {
+            return Err(parser.err("unexpected trailing input".to_string()));
+        }
+        Ok(Expr { root })
+    }
+
+    /// Variables referenced by the expression with their offsets, in order of use.
+    fn variables(&self) -> Vec<(&str, usize)> {
+        fn walk<'a>(node: &'a Node, out: &mut Vec<(&'a str, usize)>) {
+            match node {
+                Node::Num(_) => {}
+                Node::Var(name, pos) => out.push((name, *pos)),
+                Node::Neg(inner) => walk(inner, out),
+                Node::Binary(_, lhs, rhs) => {
+                    walk(lhs, out);
+                    walk(rhs, out);
+                }
+                Node::Call(_, args) => args.iter().for_each(|a| walk(a, out)),
+            }
+        }
+        let mut out = Vec::new();
+        walk(&self.root, &mut out);
+        out
+    }
+
+    fn eval(&self, env: &HashMap<&str, Vec<f64>>, v: usize) -> f64 {
+        fn go(node: &Node, env: &HashMap<&str, Vec<f64>>, v: usize) -> f64 {
+            match node {
+                Node::Num(x) => *x,
+                Node::Var(name, _) => env
+                    .get(name.as_str())
+                    .and_then(|values| values.get(v))
+                    .copied()
+                    .unwrap_or(0.0),
+                Node::Neg(inner) => -go(inner, env, v),
+                Node::Binary(op, lhs, rhs) => op.apply(go(lhs, env, v), go(rhs, env, v)),
+                Node::Call(name, args) => {
+                    let a = go(&args[0], env, v);
+                    match name.as_str() {
+                        "min" => a.min(go(&args[1], env, v)),
+                        "max" => a.max(go(&args[1], env, v)),
+                        _ => a.abs(),
+                    }
+                }
+            }
+        }
+        go(&self.root, env, v)
+    }
+}
+
+/// Compute a built-in variable for every node.
+fn builtin_values(graph: &DiGraph, name: &str, closed_set: &[bool]) -> Vec<f64> {
+    let n = graph.len();
+    let flag = |b: bool| if b { 1.0 } else { 0.0 };
+    let is_closed = |v: usize| closed_set.get(v).copied().unwrap_or(false);
+    match name {
+        "in_degree" => (0..n).map(|v| graph.in_degree(v) as f64).collect(),
+        "out_degree" => (0..n).map(|v| graph.out_degree(v) as f64).collect(),
+        "is_critical_path" => {
+            // Same set as the per-row CP badge: zero slack in open work
+            let mut values = vec![0.0; n];
+            for v in critical_path_members(graph, closed_set) {
+                values[v] = 1.0;
+            }
+            values
+        }
+        "is_closed" => (0..n).map(|v| flag(is_closed(v))).collect(),
+        "is_blocked" => (0..n)
+            .map(|v| flag(!is_closed(v) && open_blocker_count(graph, v, closed_set) > 0))
+            .collect(),
+        _ => match Metric::parse(name) {
+            Some(metric) => metric_scores(graph, metric, closed_set),
+            None => vec![0.0; n],
+        },
+    }
+}
+
+/// Evaluate an expression for every node.
+///
+/// Only the built-ins the expression references are computed. Missing
+/// entries in caller-supplied values count as 0. Division by zero follows
+/// IEEE rules, and NaN results sort last in rankings.
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `expr` - Parsed expression
+/// * `closed_set` - Boolean array indicating which nodes are closed
+/// * `values` - Caller-supplied per-node variables, parallel to node indices
+///
+/// # Returns
+/// Per-node results, or an error naming the first unknown variable.
+pub fn evaluate(
+    graph: &DiGraph,
+    expr: &Expr,
+    closed_set: &[bool],
+    values: &HashMap<String, Vec<f64>>,
+) -> Result<Vec<f64>, ExprError> {
+    let mut env: HashMap<&str, Vec<f64>> = HashMap::new();
+    for (name, pos) in expr.variables() {
+        if env.contains_key(name) {
+            continue;
+        }
+        let supplied = values.iter().find(|(k, _)| k.eq_ignore_ascii_case(name));
+        let column = match supplied {
+            Some((_, column)) => column.clone(),
+            None if BUILTINS.contains(&name) => builtin_values(graph, name, closed_set),
+            None => {
+                return Err(ExprError {
+                    message: format!("unknown variable '{}'", name),
+                    position: pos,
+                })
+            }
+        };
+        env.insert(name, column);
+    }
+    Ok((0..graph.len()).map(|v| expr.eval(&env, v)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constant(src: &str) -> f64 {
+        let mut graph = DiGraph::new();
+        graph.add_node("a");
+        let expr = Expr::parse(src).unwrap();
+        evaluate(&graph, &expr, &[], &HashMap::new()).unwrap()[0]
+    }
+
+    #[test]
+    fn test_precedence_and_functions() {
+        assert_eq!(constant("1 + 2 * 3"), 7.0);
+        assert_eq!(constant("(1 + 2) * 3"), 9.0);
+        assert_eq!(constant("-2 * -3 - 1"), 5.0);
+        assert_eq!(constant("8 / 2 / 2"), 2.0);
+        assert_eq!(constant("1 + 1 > 1"), 1.0);
+        assert_eq!(constant("2 <= 1"), 0.0);
+        assert_eq!(constant("max(1, min(5, 3)) + abs(-1)"), 4.0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Expr::parse("1 +").unwrap_err();
+        assert_eq!(err.position, 3);
+        assert_eq!(Expr::parse("2 $ 3").unwrap_err().position, 2);
+        assert_eq!(Expr::parse("(1").unwrap_err().message, "expected ')'");
+        assert!(Expr::parse("min(1)")
+            .unwrap_err()
+            .message
+            .contains("2 argument"));
+        assert!(Expr::parse("nope(1)").is_err());
+        assert!(Expr::parse("1 2").is_err());
+    }
+
+    #[test]
+    fn test_evaluate_builtins_and_values() {
+        // a -> b -> c, d isolated
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let mut values = HashMap::new();
+        values.insert("blocked_days".to_string(), vec![0.0, 3.0]);
+        let expr = Expr::parse("blocked_days + 10 * is_critical_path + is_blocked").unwrap();
+        let scores = evaluate(&graph, &expr, &[], &values).unwrap();
+        // d has slack, so it is not critical; missing blocked_days count as 0
+        assert_eq!(scores, vec![10.0, 14.0, 11.0, 0.0]);
+
+        // Closing a drops it from the critical path, which is now b -> c
+        let scores = evaluate(&graph, &expr, &[true], &values).unwrap();
+        assert_eq!(scores, vec![0.0, 13.0, 11.0, 0.0]);
+
+        let expr = Expr::parse("out_degree + unknown").unwrap();
+        let err = evaluate(&graph, &expr, &[], &values).unwrap_err();
+        assert_eq!(err.message, "unknown variable 'unknown'");
+        assert_eq!(err.position, 13);
+    }
+
+    #[test]
+    fn test_caller_values_shadow_builtins() {
+        let mut graph = DiGraph::new();
+        graph.add_node("a");
+        let mut values = HashMap::new();
+        values.insert("depth".to_string(), vec![7.0]);
+        let expr = Expr::parse("DEPTH").unwrap();
+        assert_eq!(evaluate(&graph, &expr, &[], &values).unwrap(), vec![7.0]);
+    }
+}
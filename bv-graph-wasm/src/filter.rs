@@ -0,0 +1,734 @@
+//! Structured filter query language.
+//!
+//! Parses expressions like
+//! `status:open label:auth blocked:true assignee!=me created:>2024-01-01`
+//! into a reusable [`Filter`] that can be evaluated against issue records
+//! plus graph-derived state (blocked/ready). The same filter drives the
+//! list, board, and graph views; for the graph, feed the matching indices
+//! to `extract_subgraph`.
+//!
+//! Grammar (terms are whitespace-separated and AND-ed together):
+//! - `field:value` / `field=value` – equality (case-insensitive)
+//! - `field!=value` – inequality
+//! - `field:>value`, `field:>=value`, `field:<value`, `field:<=value` – ordering
+//! - `field:a,b,c` – matches any of the listed values
+//! - `-term` – negates a term
+//! - `"quoted value"` – values containing spaces
+//! - bare words – substring match on ID or title
+//!
+//! Durations (`slack:<3d`) are in days; the `d` suffix is optional.
+
+use crate::algorithms::articulation::structural_badges;
+use crate::algorithms::kcore::kcore;
+use crate::algorithms::schedule::{cpm_schedule, node_duration};
+use crate::graph::DiGraph;
+use crate::reachability::open_blocker_count;
+use serde::Deserialize;
+use std::cell::OnceCell;
+use std::fmt;
+
+/// Issue fields a filter can test, parallel to graph node indices.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct IssueRecord {
+    pub title: String,
+    pub status: String,
+    pub priority: Option<i64>,
+    pub issue_type: String,
+    pub assignee: String,
+    pub labels: Vec<String>,
+    /// ISO-8601 timestamp (compared lexicographically, truncated to the
+    /// length of the filter value)
+    pub created_at: String,
+    /// ISO-8601 timestamp, compared like `created_at`
+    pub updated_at: String,
+    /// ISO-8601 timestamp, empty while open
+    pub closed_at: String,
+    /// Estimate in the project's unit (points or hours)
+    pub estimate: Option<f64>,
+}
+
+impl IssueRecord {
+    /// Whether the record's status counts as closed.
+    pub fn is_closed(&self) -> bool {
+        self.status.eq_ignore_ascii_case("closed")
+    }
+}
+
+/// Comparison operator in a filter term.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+/// A field that can be filtered on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Id,
+    Title,
+    Status,
+    Priority,
+    Type,
+    Assignee,
+    Label,
+    Created,
+    Updated,
+    Blocked,
+    Ready,
+    /// Articulation point of the dependency graph (not the critical path)
+    CutPoint,
+    /// Core number from k-core decomposition (`core:>=k` is the k-core)
+    Core,
+    /// CPM slack of open work, in days
+    Slack,
+}
+
+impl Field {
+    fn parse(name: &str) -> Option<Field> {
+        match name.to_ascii_lowercase().as_str() {
+            "id" => Some(Field::Id),
+            "title" => Some(Field::Title),
+            "status" => Some(Field::Status),
+            "priority" | "p" => Some(Field::Priority),
+            "type" => Some(Field::Type),
+            "assignee" => Some(Field::Assignee),
+            "label" | "labels" => Some(Field::Label),
+            "created" => Some(Field::Created),
+            "updated" => Some(Field::Updated),
+            "blocked" => Some(Field::Blocked),
+            "ready" => Some(Field::Ready),
+            "cutpoint" => Some(Field::CutPoint),
+            "core" | "kcore" => Some(Field::Core),
+            "slack" | "float" => Some(Field::Slack),
+            _ => None,
+        }
+    }
+
+    fn is_bool(self) -> bool {
+        matches!(self, Field::Blocked | Field::Ready | Field::CutPoint)
+    }
+}
+
+/// One parsed term of a filter expression.
+#[derive(Debug, Clone)]
+pub enum Term {
+    /// `field<op>value[,value...]`
+    Compare {
+        field: Field,
+        op: Op,
+        values: Vec<String>,
+        negated: bool,
+    },
+    /// Bare word matched against ID and title
+    Text { text: String, negated: bool },
+}
+
+/// Error produced while parsing a filter expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterError {
+    /// Human-readable description
+    pub message: String,
+    /// Byte offset of the offending term in the query
+    pub position: usize,
+}
+
+impl fmt::Display for FilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at {})", self.message, self.position)
+    }
+}
+
+/// Evaluation context shared by all terms.
+///
+/// Graph analyses behind `cutpoint`, `core` and `slack` terms run on first
+/// use, so queries that never reference them (the common case while typing
+/// in the filter bar) only pay for the closed set.
+pub struct FilterContext<'a> {
+    pub graph: &'a DiGraph,
+    pub records: &'a [IssueRecord],
+    /// Value substituted for `me` in assignee terms
+    pub me: Option<&'a str>,
+    durations: &'a [f64],
+    closed: Vec<bool>,
+    cut_point: OnceCell<Vec<bool>>,
+    core: OnceCell<Vec<u32>>,
+    /// Slack per node; None for closed nodes and for cyclic graphs
+    slack: OnceCell<Vec<Option<f64>>>,
+}
+
+impl<'a> FilterContext<'a> {
+    /// Build the context; `durations` are per-node estimates in days for slack
+    /// terms (missing entries use the CPM default).
+    pub fn new(
+        graph: &'a DiGraph,
+        records: &'a [IssueRecord],
+        me: Option<&'a str>,
+        durations: &'a [f64],
+    ) -> Self {
+        let closed = (0..graph.len())
+            .map(|v| records.get(v).is_some_and(IssueRecord::is_closed))
+            .collect();
+        FilterContext {
+            graph,
+            records,
+            me,
+            durations,
+            closed,
+            cut_point: OnceCell::new(),
+            core: OnceCell::new(),
+            slack: OnceCell::new(),
+        }
+    }
+
+    fn cut_point(&self) -> &[bool] {
+        self.cut_point
+            .get_or_init(|| structural_badges(self.graph).cut_point)
+    }
+
+    fn core(&self) -> &[u32] {
+        self.core.get_or_init(|| kcore(self.graph))
+    }
+
+    fn slack(&self) -> &[Option<f64>] {
+        self.slack.get_or_init(|| {
+            let n = self.graph.len();
+            // Closed work takes no time, so slack reflects what is left to do
+            let remaining: Vec<f64> = (0..n)
+                .map(|v| {
+                    if self.closed[v] {
+                        0.0
+                    } else {
+                        node_duration(self.durations, v)
+                    }
+                })
+                .collect();
+            let schedule = cpm_schedule(self.graph, &remaining);
+            (0..n)
+                .map(|v| match schedule.entries.get(v) {
+                    Some(entry) if !self.closed[v] => Some(entry.slack),
+                    _ => None,
+                })
+                .collect()
+        })
+    }
+}
+
+/// A parsed, reusable filter expression.
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    pub terms: Vec<Term>,
+}
+
+impl Filter {
+    /// Parse a filter expression. An empty query matches everything.
+    pub fn parse(query: &str) -> Result<Filter, FilterError> {
+        let terms = tokenize(query)?
+            .into_iter()
+            .map(|(pos, tok)| parse_term(pos, &tok))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Filter { terms })
+    }
+
+    /// Check whether a node satisfies every term.
+    pub fn matches(&self, ctx: &FilterContext, node: usize) -> bool {
+        self.terms.iter().all(|t| term_matches(t, ctx, node))
+    }
+}
+
+/// Split a query into (offset, token) pairs, honoring double quotes.
+fn tokenize(query: &str) -> Result<Vec<(usize, String)>, FilterError> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut quote_pos = 0;
+
+    for (i, c) in query.char_indices() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                quote_pos = i;
+                if current.is_empty() {
+                    start = i;
+                }
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if !current.is_empty() {
+                    tokens.push((start, std::mem::take(&mut current)));
+                }
+            }
+            c => {
+                if current.is_empty() {
+                    start = i;
+                }
+                current.push(c);
+            }
+        }
+    }
+    if in_quotes {
+        return Err(FilterError {
+            message: "unterminated quote".to_string(),
+            position: quote_pos,
+        });
+    }
+    if !current.is_empty() {
+        tokens.push((start, current));
+    }
+    Ok(tokens)
+}
+
+fn parse_term(pos: usize, token: &str) -> Result<Term, FilterError> {
+    let err = |message: String| FilterError {
+        message,
+        position: pos,
+    };
+
+    let (negated, body) = match token.strip_prefix('-') {
+        Some(rest) if !rest.is_empty() => (true, rest),
+        _ => (false, token),
+    };
+
+    // Locate the first operator character
+    let split = body.find([':', '=', '!']);
+    let Some(idx) = split else {
+        return Ok(Term::Text {
+            text: body.to_lowercase(),
+            negated,
+        });
+    };
+
+    let name = &body[..idx];
+    let rest = &body[idx..];
+    let (op, value) = if let Some(v) = rest.strip_prefix("!=") {
+        (Op::Ne, v)
+    } else if let Some(v) = rest.strip_prefix(":>=") {
+        (Op::Ge, v)
+    } else if let Some(v) = rest.strip_prefix(":<=") {
+        (Op::Le, v)
+    } else if let Some(v) = rest.strip_prefix(":>") {
+        (Op::Gt, v)
+    } else if let Some(v) = rest.strip_prefix(":<") {
+        (Op::Lt, v)
+    } else if let Some(v) = rest.strip_prefix(':').or(rest.strip_prefix('=')) {
+        (Op::Eq, v)
+    } else {
+        return Err(err(format!("invalid operator in '{}'", token)));
+    };
+
+    let field = Field::parse(name).ok_or_else(|| err(format!("unknown field '{}'", name)))?;
+    if value.is_empty() {
+        return Err(err(format!("missing value for '{}'", name)));
+    }
+
+    let values: Vec<String> = value
+        .split(',')
+        .filter(|v| !v.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+
+    if field.is_bool() {
+        if !matches!(op, Op::Eq | Op::Ne) {
+            return Err(err(format!("'{}' only supports ':' and '!='", name)));
+        }
+        if values.iter().any(|v| v != "true" && v != "false") {
+            return Err(err(format!("'{}' expects true or false", name)));
+        }
+    }
+    if field == Field::Priority && values.iter().any(|v| parse_priority(v).is_none()) {
+        return Err(err(format!("invalid priority '{}'", value)));
+    }
+    if field == Field::Core && values.iter().any(|v| v.parse::<u32>().is_err()) {
+        return Err(err(format!("invalid core number '{}'", value)));
+    }
+    if field == Field::Slack && values.iter().any(|v| parse_days(v).is_none()) {
+        return Err(err(format!("invalid duration '{}'", value)));
+    }
+
+    Ok(Term::Compare {
+        field,
+        op,
+        values,
+        negated,
+    })
+}
+
+/// Accept both `2` and `p2` spellings.
+fn parse_priority(v: &str) -> Option<i64> {
+    v.trim_start_matches('p').parse().ok()
+}
+
+/// Accept `3`, `3d` and `0.5d`.
+fn parse_days(v: &str) -> Option<f64> {
+    v.strip_suffix('d')
+        .unwrap_or(v)
+        .parse::<f64>()
+        .ok()
+        .filter(|d| d.is_finite())
+}
+
+fn term_matches(term: &Term, ctx: &FilterContext, node: usize) -> bool {
+    let default = IssueRecord::default();
+    let record = ctx.records.get(node).unwrap_or(&default);
+
+    match term {
+        Term::Text { text, negated } => {
+            let id = ctx.graph.node_id(node).unwrap_or_default().to_lowercase();
+            let hit =
+                id.contains(text.as_str()) || record.title.to_lowercase().contains(text.as_str());
+            hit != *negated
+        }
+        Term::Compare {
+            field,
+            op,
+            values,
+            negated,
+        } => {
+            let hit = match field {
+                Field::Blocked | Field::Ready => {
+                    let blocked = open_blocker_count(ctx.graph, node, &ctx.closed) > 0;
+                    let actual = match field {
+                        Field::Blocked => blocked,
+                        _ => !blocked && !ctx.closed.get(node).copied().unwrap_or(false),
+                    };
+                    let any_eq = values.iter().any(|v| (v == "true") == actual);
+                    compare_eq(*op, any_eq)
+                }
+                Field::CutPoint => {
+                    let actual = ctx.cut_point()[node];
+                    compare_eq(*op, values.iter().any(|v| (v == "true") == actual))
+                }
+                Field::Core => values
+                    .iter()
+                    .filter_map(|v| v.parse::<u32>().ok())
+                    .any(|want| compare_ord(*op, ctx.core()[node].cmp(&want))),
+                Field::Slack => match ctx.slack()[node] {
+                    Some(slack) => values.iter().filter_map(|v| parse_days(v)).any(|want| {
+                        let ord = slack
+                            .partial_cmp(&want)
+                            .unwrap_or(std::cmp::Ordering::Equal);
+                        compare_ord(*op, ord)
+                    }),
+                    None => false,
+                },
+                Field::Priority => match record.priority {
+                    Some(p) => values
+                        .iter()
+                        .filter_map(|v| parse_priority(v))
+                        .any(|want| compare_ord(*op, p.cmp(&want))),
+                    None => false,
+                },
+                Field::Label => {
+                    let any_eq = values
+                        .iter()
+                        .any(|v| record.labels.iter().any(|l| l.eq_ignore_ascii_case(v)));
+                    compare_eq(*op, any_eq)
+                }
+                Field::Assignee => {
+                    let actual = record.assignee.to_lowercase();
+                    let any_eq = values.iter().any(|v| {
+                        let want = match (v.as_str(), ctx.me) {
+                            ("me", Some(me)) => me.to_lowercase(),
+                            _ => v.clone(),
+                        };
+                        actual == want
+                    });
+                    compare_eq(*op, any_eq)
+                }
+                _ => {
+                    let actual = match field {
+                        Field::Id => ctx.graph.node_id(node).unwrap_or_default(),
+                        Field::Title => record.title.clone(),
+                        Field::Status => record.status.clone(),
+                        Field::Type => record.issue_type.clone(),
+                        Field::Created => record.created_at.clone(),
+                        _ => record.updated_at.clone(),
+                    }
+                    .to_lowercase();
+                    // Timestamps compare at the value's precision, so
+                    // `created:2024-03-01` covers that whole day
+                    let is_date = matches!(field, Field::Created | Field::Updated);
+                    let at_precision = |v: &str| -> &str {
+                        match actual.get(..v.len()) {
+                            Some(prefix) if is_date => prefix,
+                            _ => &actual,
+                        }
+                    };
+                    match op {
+                        Op::Eq | Op::Ne => {
+                            compare_eq(*op, values.iter().any(|v| at_precision(v) == v))
+                        }
+                        _ => {
+                            !actual.is_empty()
+                                && values
+                                    .iter()
+                                    .any(|v| compare_ord(*op, at_precision(v).cmp(v.as_str())))
+                        }
+                    }
+                }
+            };
+            hit != *negated
+        }
+    }
+}
+
+fn compare_eq(op: Op, any_eq: bool) -> bool {
+    match op {
+        Op::Ne => !any_eq,
+        _ => any_eq,
+    }
+}
+
+fn compare_ord(op: Op, ord: std::cmp::Ordering) -> bool {
+    use std::cmp::Ordering::*;
+    match op {
+        Op::Eq => ord == Equal,
+        Op::Ne => ord != Equal,
+        Op::Gt => ord == Greater,
+        Op::Ge => ord != Less,
+        Op::Lt => ord == Less,
+        Op::Le => ord != Greater,
+    }
+}
+
+/// Evaluate a filter over all nodes and return the matching indices.
+pub fn filter_nodes(
+    graph: &DiGraph,
+    filter: &Filter,
+    records: &[IssueRecord],
+    me: Option<&str>,
+    durations: &[f64],
+) -> Vec<usize> {
+    let ctx = FilterContext::new(graph, records, me, durations);
+    (0..graph.len())
+        .filter(|&v| filter.matches(&ctx, v))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(
+        status: &str,
+        labels: &[&str],
+        assignee: &str,
+        priority: i64,
+        created: &str,
+    ) -> IssueRecord {
+        IssueRecord {
+            title: format!("{} task", status),
+            status: status.to_string(),
+            priority: Some(priority),
+            issue_type: "task".to_string(),
+            assignee: assignee.to_string(),
+            labels: labels.iter().map(|l| l.to_string()).collect(),
+            created_at: created.to_string(),
+            updated_at: created.to_string(),
+            closed_at: String::new(),
+            estimate: None,
+        }
+    }
+
+    /// a(closed) -> b(open), c(open) -> d(open)
+    fn fixture() -> (DiGraph, Vec<IssueRecord>) {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("bv-1");
+        let b = graph.add_node("bv-2");
+        let c = graph.add_node("bv-3");
+        let d = graph.add_node("bv-4");
+        graph.add_edge(a, b);
+        graph.add_edge(c, d);
+        let records = vec![
+            record("closed", &["auth"], "alice", 1, "2023-12-01"),
+            record("open", &["auth", "ui"], "bob", 0, "2024-02-01"),
+            record("in_progress", &["ui"], "alice", 2, "2024-03-01"),
+            record("open", &[], "", 3, "2024-01-15"),
+        ];
+        (graph, records)
+    }
+
+    fn run(query: &str) -> Vec<usize> {
+        let (graph, records) = fixture();
+        let filter = Filter::parse(query).unwrap();
+        filter_nodes(&graph, &filter, &records, Some("alice"), &[])
+    }
+
+    #[test]
+    fn test_parse_empty_matches_all() {
+        assert_eq!(run(""), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_status_and_label() {
+        assert_eq!(run("status:open"), vec![1, 3]);
+        assert_eq!(run("status:open label:auth"), vec![1]);
+        assert_eq!(run("status:open,in_progress"), vec![1, 2, 3]);
+        assert_eq!(run("-label:ui"), vec![0, 3]);
+    }
+
+    #[test]
+    fn test_assignee_me() {
+        assert_eq!(run("assignee:me"), vec![0, 2]);
+        assert_eq!(run("assignee!=me"), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_blocked_and_ready() {
+        // bv-4 is blocked by open bv-3; bv-2's blocker is closed
+        assert_eq!(run("blocked:true"), vec![3]);
+        assert_eq!(run("ready:true"), vec![1, 2]);
+        assert_eq!(run("blocked:false status:open"), vec![1]);
+    }
+
+    #[test]
+    fn test_cut_points() {
+        assert!(run("cutpoint:true").is_empty());
+        // "critical" means the critical path elsewhere, so it is not an alias
+        assert!(Filter::parse("critical:true").is_err());
+
+        // b joins a and c: it is the only cut point
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        let filter = Filter::parse("cutpoint:true").unwrap();
+        assert_eq!(filter_nodes(&graph, &filter, &[], None, &[]), vec![b]);
+        let filter = Filter::parse("cutpoint!=true").unwrap();
+        assert_eq!(filter_nodes(&graph, &filter, &[], None, &[]), vec![a, c]);
+    }
+
+    #[test]
+    fn test_core_lens() {
+        // Triangle a, b, c with a leaf d hanging off c
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(a, c);
+        graph.add_edge(c, d);
+        let run_on = |query: &str| {
+            let filter = Filter::parse(query).unwrap();
+            filter_nodes(&graph, &filter, &[], None, &[])
+        };
+        assert_eq!(run_on("core:>=2"), vec![a, b, c]);
+        assert_eq!(run_on("kcore:1"), vec![d]);
+        assert!(run_on("core:>=3").is_empty());
+        assert!(Filter::parse("core:>=dense").is_err());
+    }
+
+    #[test]
+    fn test_slack_at_risk() {
+        // a(2d) -> b(1d) -> c(1d) plus the shortcut a -> c; d is closed and independent
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, c);
+        let mut records = vec![IssueRecord::default(); 4];
+        records[3].status = "closed".to_string();
+        let durations = [2.0, 1.0, 1.0, 5.0];
+        let run_on = |query: &str, durations: &[f64]| {
+            let filter = Filter::parse(query).unwrap();
+            filter_nodes(&graph, &filter, &records, None, durations)
+        };
+        // a -> b -> c is critical; the closed node never matches
+        assert_eq!(run_on("slack:0", &durations), vec![a, b, c]);
+        assert!(run_on("slack:>0d", &durations).is_empty());
+
+        // With a -> c and b -> c only, b has float of a's duration minus its own
+        let filter = Filter::parse("slack:<3d").unwrap();
+        let mut graph = DiGraph::new();
+        for id in ["a", "b", "c"] {
+            graph.add_node(id);
+        }
+        graph.add_edge(0, 2);
+        graph.add_edge(1, 2);
+        let at_risk = filter_nodes(&graph, &filter, &[], None, &[5.0, 1.0, 1.0]);
+        assert_eq!(at_risk, vec![0, 2]);
+        let at_risk = filter_nodes(&graph, &filter, &[], None, &[3.5, 1.0, 1.0]);
+        assert_eq!(at_risk, vec![0, 1, 2]);
+        assert!(Filter::parse("slack:<soon").is_err());
+    }
+
+    #[test]
+    fn test_graph_analyses_run_on_demand() {
+        let (graph, records) = fixture();
+        let ctx = FilterContext::new(&graph, &records, None, &[]);
+        let filter = Filter::parse("status:open").unwrap();
+        assert!((0..graph.len()).any(|v| filter.matches(&ctx, v)));
+        assert!(ctx.cut_point.get().is_none());
+        assert!(ctx.core.get().is_none());
+        assert!(ctx.slack.get().is_none());
+
+        // c -> d is the longest open chain
+        let filter = Filter::parse("slack:0").unwrap();
+        let matched: Vec<usize> = (0..graph.len())
+            .filter(|&v| filter.matches(&ctx, v))
+            .collect();
+        assert_eq!(matched, vec![2, 3]);
+        assert!(ctx.slack.get().is_some());
+        assert!(ctx.core.get().is_none());
+    }
+
+    #[test]
+    fn test_ordering_operators() {
+        assert_eq!(run("created:>2024-01-01"), vec![1, 2, 3]);
+        assert_eq!(run("created:<=2024-01-15"), vec![0, 3]);
+        assert_eq!(run("priority:<=1"), vec![0, 1]);
+        assert_eq!(run("p:p2"), vec![2]);
+    }
+
+    #[test]
+    fn test_dates_against_full_timestamps() {
+        let mut graph = DiGraph::new();
+        graph.add_node("bv-1");
+        graph.add_node("bv-2");
+        let mut records = vec![
+            record("open", &[], "", 1, "2024-03-01T10:00:00Z"),
+            record("open", &[], "", 1, "2024-02-29T23:59:59Z"),
+        ];
+        records[1].updated_at = "2024-03-02T08:00:00Z".to_string();
+        let run_on = |query: &str| {
+            let filter = Filter::parse(query).unwrap();
+            filter_nodes(&graph, &filter, &records, None, &[])
+        };
+        assert_eq!(run_on("created:2024-03-01"), vec![0]);
+        assert_eq!(run_on("created:<=2024-03-01"), vec![0, 1]);
+        assert_eq!(run_on("created:>=2024-03-01"), vec![0]);
+        assert!(run_on("created:>2024-03-01").is_empty());
+        assert_eq!(run_on("created:<2024-03-01"), vec![1]);
+        assert_eq!(run_on("created!=2024-03-01"), vec![1]);
+        assert_eq!(run_on("updated:2024-03"), vec![0, 1]);
+        assert_eq!(run_on("updated:>2024-03-01"), vec![1]);
+    }
+
+    #[test]
+    fn test_bare_words_and_quotes() {
+        assert_eq!(run("bv-3"), vec![2]);
+        assert_eq!(run("title:\"open task\""), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Filter::parse("colour:red").is_err());
+        assert!(Filter::parse("status:").is_err());
+        assert!(Filter::parse("blocked:maybe").is_err());
+        assert!(Filter::parse("blocked:>true").is_err());
+        assert!(Filter::parse("priority:high").is_err());
+        let err = Filter::parse("status:open title:\"oops").unwrap_err();
+        assert_eq!(err.message, "unterminated quote");
+    }
+}
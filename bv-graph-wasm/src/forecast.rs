@@ -0,0 +1,264 @@
+//! Probabilistic completion forecasts from historical throughput.
+//!
+//! A Monte Carlo simulation replays past weeks: each simulated week draws
+//! the throughput of a random historical week and subtracts it from the
+//! remaining work until nothing is left. Repeating this many times gives
+//! a distribution of finish dates, reported as percentiles ("85% chance
+//! to land by ...").
+//!
+//! Throughput alone ignores dependencies, so every trial is also floored
+//! at the remaining critical path: work that must happen in sequence
+//! cannot finish sooner, however many issues close per week.
+
+use crate::algorithms::schedule::{cpm_schedule, node_duration};
+use crate::algorithms::subgraph::extract_subgraph;
+use crate::export::ical::{civil_from_days, parse_date};
+use crate::filter::IssueRecord;
+use crate::graph::DiGraph;
+use serde::Serialize;
+
+/// Percentiles reported for every forecast.
+const PERCENTILES: [u32; 3] = [50, 85, 95];
+
+/// Simulated weeks after which a trial gives up (about ten years).
+const MAX_WEEKS: usize = 520;
+
+/// Finish date at one confidence level.
+#[derive(Debug, Clone, Serialize)]
+pub struct ForecastPoint {
+    /// Share of trials finished by this date, in percent
+    pub percentile: u32,
+    /// Days from the as-of date
+    pub days: i64,
+    /// Finish date as `YYYY-MM-DD`
+    pub date: String,
+}
+
+/// Result of a throughput forecast.
+#[derive(Debug, Clone, Serialize)]
+pub struct Forecast {
+    /// Work closed per week (issues or points), oldest week first
+    pub throughput: Vec<f64>,
+    /// Remaining open work in the same unit
+    pub remaining: f64,
+    /// Remaining critical path in days, the floor for every trial
+    pub critical_path_days: f64,
+    /// Finish dates by percentile; empty when there is no throughput to
+    /// forecast from
+    pub percentiles: Vec<ForecastPoint>,
+}
+
+/// Format days since the epoch as `YYYY-MM-DD`.
+fn format_day(days: i64) -> String {
+    let (y, m, d) = civil_from_days(days);
+    format!("{:04}-{:02}-{:02}", y, m, d)
+}
+
+/// Forecast when the open work will be done.
+///
+/// A closed issue counts in the week of its `closed_at` (falling back to
+/// `updated_at`). With `points`, throughput and remaining work sum record
+/// estimates, and unestimated issues count as 0; otherwise every issue
+/// counts as 1. The critical path uses `durations` in days, with the
+/// CPM default for missing entries, and is 0 when open work is cyclic.
+///
+/// Returns None if `as_of` is not a date.
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `records` - Issue records parallel to node indices (status drives closed state)
+/// * `durations` - Per-node durations in days for the critical-path floor
+/// * `as_of` - Forecast date; the last history week ends on it
+/// * `history_weeks` - Number of past weeks to sample throughput from
+/// * `points` - Measure work in estimate points instead of issue counts
+/// * `trials` - Number of Monte Carlo trials
+/// * `seed` - Optional seed for deterministic results (None for random)
+#[allow(clippy::too_many_arguments)]
+pub fn forecast(
+    graph: &DiGraph,
+    records: &[IssueRecord],
+    durations: &[f64],
+    as_of: &str,
+    history_weeks: usize,
+    points: bool,
+    trials: usize,
+    seed: Option<u64>,
+) -> Option<Forecast> {
+    let today = parse_date(as_of)?;
+    let n = graph.len();
+    let closed: Vec<bool> = (0..n)
+        .map(|v| records.get(v).is_some_and(IssueRecord::is_closed))
+        .collect();
+    let work = |v: usize| {
+        if points {
+            records.get(v).and_then(|r| r.estimate).unwrap_or(0.0)
+        } else {
+            1.0
+        }
+    };
+
+    let mut throughput = vec![0.0; history_weeks];
+    for v in (0..n).filter(|&v| closed[v]) {
+        let r = &records[v];
+        let at = if r.closed_at.is_empty() {
+            &r.updated_at
+        } else {
+            &r.closed_at
+        };
+        let Some(day) = parse_date(at).filter(|&d| d <= today) else {
+            continue;
+        };
+        let weeks_ago = ((today - day) / 7) as usize;
+        if weeks_ago < history_weeks {
+            throughput[history_weeks - 1 - weeks_ago] += work(v);
+        }
+    }
+
+    let open: Vec<usize> = (0..n).filter(|&v| !closed[v]).collect();
+    let remaining: f64 = open.iter().map(|&v| work(v)).sum();
+    // extract_subgraph renumbers in input order, so sub index i == open[i]
+    let open_durations: Vec<f64> = open.iter().map(|&v| node_duration(durations, v)).collect();
+    let critical_path_days =
+        cpm_schedule(&extract_subgraph(graph, &open), &open_durations).project_duration;
+    let floor = critical_path_days.ceil() as i64;
+
+    let mut result = Forecast {
+        throughput,
+        remaining,
+        critical_path_days,
+        percentiles: Vec::new(),
+    };
+    let can_progress = result.throughput.iter().any(|&t| t > 0.0);
+    if trials == 0 || (remaining > 0.0 && !can_progress) {
+        return Some(result);
+    }
+
+    let mut rng_state = match seed {
+        Some(s) => s,
+        None => {
+            let mut buf = [0u8; 8];
+            let _ = getrandom::getrandom(&mut buf);
+            u64::from_le_bytes(buf)
+        }
+    };
+    let mut lcg = || {
+        rng_state = rng_state.wrapping_mul(6364136223846793005).wrapping_add(1);
+        (rng_state >> 33) as usize
+    };
+
+    let weeks = &result.throughput;
+    let mut days: Vec<i64> = (0..trials)
+        .map(|_| {
+            let mut left = remaining;
+            let mut elapsed = 0;
+            while left > 1e-9 && elapsed < MAX_WEEKS {
+                left -= weeks[lcg() % weeks.len()];
+                elapsed += 1;
+            }
+            (elapsed as i64 * 7).max(floor)
+        })
+        .collect();
+    days.sort_unstable();
+
+    result.percentiles = PERCENTILES
+        .iter()
+        .map(|&p| {
+            let rank = (p as usize * trials).div_ceil(100).max(1);
+            let d = days[rank - 1];
+            ForecastPoint {
+                percentile: p,
+                days: d,
+                date: format_day(today + d),
+            }
+        })
+        .collect();
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(status: &str, closed_at: &str, estimate: Option<f64>) -> IssueRecord {
+        IssueRecord {
+            status: status.to_string(),
+            closed_at: closed_at.to_string(),
+            estimate,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_forecast_steady_throughput() {
+        // Two issues closed in each of the last two weeks, four open
+        let mut graph = DiGraph::new();
+        for id in ["c1", "c2", "c3", "c4", "o1", "o2", "o3", "o4"] {
+            graph.add_node(id);
+        }
+        let mut records = vec![
+            record("closed", "2024-03-04", None),
+            record("closed", "2024-03-06", None),
+            record("closed", "2024-03-12", None),
+            record("closed", "2024-03-14T09:00:00Z", None),
+        ];
+        records.extend((0..4).map(|_| record("open", "", None)));
+
+        let result = forecast(&graph, &records, &[], "2024-03-14", 2, false, 200, Some(7));
+        let result = result.unwrap();
+        assert_eq!(result.throughput, vec![2.0, 2.0]);
+        assert_eq!(result.remaining, 4.0);
+        // Every trial takes exactly two weeks
+        let dates: Vec<&str> = result.percentiles.iter().map(|p| p.date.as_str()).collect();
+        assert_eq!(dates, vec!["2024-03-28"; 3]);
+        assert!(forecast(&graph, &records, &[], "someday", 2, false, 10, None).is_none());
+    }
+
+    #[test]
+    fn test_forecast_critical_path_floor_and_points() {
+        // a -> b -> c open, 10 days each; one 8-point issue closed last week
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_node("done");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        let records = vec![
+            record("open", "", Some(1.0)),
+            record("open", "", Some(1.0)),
+            record("open", "", None),
+            record("closed", "2024-01-09", Some(8.0)),
+        ];
+
+        let durations = [10.0, 10.0, 10.0];
+        let result = forecast(
+            &graph,
+            &records,
+            &durations,
+            "2024-01-10",
+            1,
+            true,
+            50,
+            Some(1),
+        );
+        let result = result.unwrap();
+        assert_eq!(result.remaining, 2.0);
+        assert_eq!(result.critical_path_days, 30.0);
+        // Throughput alone would finish in a week; the chain takes 30 days
+        assert!(result.percentiles.iter().all(|p| p.days == 30));
+        assert_eq!(result.percentiles[0].date, "2024-02-09");
+
+        // Nothing closed in the window: no forecast
+        let result = forecast(
+            &graph,
+            &records,
+            &durations,
+            "2024-06-01",
+            1,
+            true,
+            50,
+            Some(1),
+        );
+        assert!(result.unwrap().percentiles.is_empty());
+    }
+}
@@ -0,0 +1,248 @@
+//! Fuzzy matching for jump-to-issue search.
+//!
+//! fzf-style scoring: the query must appear as a case-insensitive
+//! subsequence of the candidate. Matches earn points per character, with
+//! bonuses for consecutive runs and word-boundary hits and a small penalty
+//! for gaps, so "bv12" ranks "bv-12" above "bv-1-alpha-2".
+
+use crate::graph::DiGraph;
+use serde::Serialize;
+
+const SCORE_MATCH: i32 = 16;
+const BONUS_CONSECUTIVE: i32 = 8;
+const BONUS_BOUNDARY: i32 = 10;
+const BONUS_FIRST_CHAR: i32 = 6;
+const PENALTY_GAP: i32 = 1;
+const MAX_GAP_PENALTY: i32 = 12;
+
+/// Which field of the issue matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchField {
+    Id,
+    Title,
+}
+
+/// A single fuzzy match.
+#[derive(Debug, Clone, Serialize)]
+pub struct FuzzyMatch {
+    /// Node index in the graph
+    pub node: usize,
+    /// Node ID for display
+    pub id: Option<String>,
+    /// Match score (higher is better)
+    pub score: i32,
+    /// Field the best match was found in
+    pub field: MatchField,
+    /// Character positions of matched query characters (for highlighting)
+    pub positions: Vec<usize>,
+}
+
+/// Lowercase `text` the same way for queries and candidates, returning the
+/// folded characters and the index of the source character each came from
+/// (some characters, such as 'İ', lowercase to more than one).
+fn fold_case(text: &str) -> (Vec<char>, Vec<usize>) {
+    text.chars()
+        .enumerate()
+        .flat_map(|(i, c)| c.to_lowercase().map(move |l| (l, i)))
+        .unzip()
+}
+
+/// Score `candidate` against `query`.
+///
+/// Returns the score and matched character positions, or None when the
+/// query is not a subsequence of the candidate.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<(i32, Vec<usize>)> {
+    let (query, _) = fold_case(query);
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+    let chars: Vec<char> = candidate.chars().collect();
+    let (lower, origin) = fold_case(candidate);
+
+    // Find the shortest window ending at the earliest full match: scan forward
+    // for the end, then backward from there to tighten the start (fzf v1).
+    let mut qi = 0;
+    let mut end = None;
+    for (i, &c) in lower.iter().enumerate() {
+        if c == query[qi] {
+            qi += 1;
+            if qi == query.len() {
+                end = Some(i);
+                break;
+            }
+        }
+    }
+    let end = end?;
+
+    let mut qi = query.len();
+    let mut start = end;
+    for i in (0..=end).rev() {
+        if lower[i] == query[qi - 1] {
+            qi -= 1;
+            if qi == 0 {
+                start = i;
+                break;
+            }
+        }
+    }
+
+    // Forward pass inside the window to collect positions and score; `i`
+    // indexes folded characters, `origin[i]` the candidate character
+    let mut positions: Vec<usize> = Vec::with_capacity(query.len());
+    let mut score = 0;
+    let mut qi = 0;
+    let mut prev: Option<usize> = None;
+    for (i, &c) in lower.iter().enumerate().take(end + 1).skip(start) {
+        if qi < query.len() && c == query[qi] {
+            score += SCORE_MATCH;
+            let first_fold = i == 0 || origin[i - 1] != origin[i];
+            if first_fold && is_boundary(&chars, origin[i]) {
+                score += BONUS_BOUNDARY;
+            }
+            match prev {
+                Some(p) if p + 1 == i => score += BONUS_CONSECUTIVE,
+                Some(p) => score -= ((i - p - 1) as i32 * PENALTY_GAP).min(MAX_GAP_PENALTY),
+                None if i == 0 => score += BONUS_FIRST_CHAR,
+                None => {}
+            }
+            if positions.last() != Some(&origin[i]) {
+                positions.push(origin[i]);
+            }
+            prev = Some(i);
+            qi += 1;
+        }
+    }
+
+    Some((score, positions))
+}
+
+/// A character starts a word if it is first, follows a separator, or is an
+/// uppercase letter following a lowercase one (camelCase).
+fn is_boundary(chars: &[char], i: usize) -> bool {
+    if i == 0 {
+        return true;
+    }
+    let prev = chars[i - 1];
+    !prev.is_alphanumeric() || (prev.is_lowercase() && chars[i].is_uppercase())
+}
+
+/// Fuzzy-find issues by ID and (optionally) title.
+///
+/// Each node is scored against its ID and its title (when provided); the
+/// better of the two is kept. Results are sorted by score descending, then
+/// node index. An empty query returns nodes in index order.
+///
+/// # Arguments
+/// * `graph` - The graph (provides node IDs)
+/// * `query` - Search text
+/// * `titles` - Per-node titles indexed by node (may be shorter than node count)
+/// * `limit` - Maximum number of matches to return
+pub fn fuzzy_find(
+    graph: &DiGraph,
+    query: &str,
+    titles: &[String],
+    limit: usize,
+) -> Vec<FuzzyMatch> {
+    let mut matches: Vec<FuzzyMatch> = (0..graph.len())
+        .filter_map(|v| {
+            let id = graph.node_id(v)?;
+            let by_id = fuzzy_score(query, &id).map(|(s, p)| (s, MatchField::Id, p));
+            let by_title = titles
+                .get(v)
+                .and_then(|t| fuzzy_score(query, t))
+                .map(|(s, p)| (s, MatchField::Title, p));
+
+            let best = match (by_id, by_title) {
+                (Some(a), Some(b)) => Some(if b.0 > a.0 { b } else { a }),
+                (a, b) => a.or(b),
+            };
+
+            best.map(|(score, field, positions)| FuzzyMatch {
+                node: v,
+                id: Some(id),
+                score,
+                field,
+                positions,
+            })
+        })
+        .collect();
+
+    matches.sort_by(|a, b| b.score.cmp(&a.score).then(a.node.cmp(&b.node)));
+    matches.truncate(limit);
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score_no_match() {
+        assert!(fuzzy_score("xyz", "bv-123").is_none());
+        assert!(fuzzy_score("321", "bv-123").is_none());
+    }
+
+    #[test]
+    fn test_fuzzy_score_positions() {
+        let (_, positions) = fuzzy_score("bv12", "bv-123").unwrap();
+        assert_eq!(positions, vec![0, 1, 3, 4]);
+    }
+
+    #[test]
+    fn test_fuzzy_score_case_insensitive() {
+        assert!(fuzzy_score("AUTH", "fix auth flow").is_some());
+        assert!(fuzzy_score("auth", "Fix AUTH flow").is_some());
+        // 'İ' lowercases to two characters on both sides
+        let (_, positions) = fuzzy_score("İstanbul", "Visit İSTANBUL").unwrap();
+        assert_eq!(positions, (6..14).collect::<Vec<_>>());
+        assert_eq!(fuzzy_score("is", "ax İs").unwrap().1, vec![3, 4]);
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_consecutive() {
+        let (tight, _) = fuzzy_score("bv12", "bv-12").unwrap();
+        let (loose, _) = fuzzy_score("bv12", "bv-1-alpha-2").unwrap();
+        assert!(tight > loose);
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_boundaries() {
+        let (boundary, _) = fuzzy_score("lp", "label-picker").unwrap();
+        let (inner, _) = fuzzy_score("lp", "helper").unwrap();
+        assert!(boundary > inner);
+    }
+
+    #[test]
+    fn test_fuzzy_find_ranks_by_id_and_title() {
+        let mut graph = DiGraph::new();
+        graph.add_node("bv-1");
+        graph.add_node("bv-2");
+        graph.add_node("bv-3");
+        let titles = vec![
+            "Refactor loader".to_string(),
+            "Fix login timeout".to_string(),
+            "Docs".to_string(),
+        ];
+
+        let results = fuzzy_find(&graph, "login", &titles, 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].node, 1);
+        assert_eq!(results[0].field, MatchField::Title);
+
+        let results = fuzzy_find(&graph, "bv3", &titles, 10);
+        assert_eq!(results[0].node, 2);
+        assert_eq!(results[0].field, MatchField::Id);
+    }
+
+    #[test]
+    fn test_fuzzy_find_empty_query_and_limit() {
+        let mut graph = DiGraph::new();
+        for i in 0..5 {
+            graph.add_node(&format!("bv-{}", i));
+        }
+        let results = fuzzy_find(&graph, "", &[], 3);
+        let nodes: Vec<usize> = results.iter().map(|m| m.node).collect();
+        assert_eq!(nodes, vec![0, 1, 2]);
+    }
+}
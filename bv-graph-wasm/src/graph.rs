@@ -92,6 +92,25 @@ impl DiGraph {
         self.edge_count += 1;
     }
 
+    /// Remove the directed edge from -> to. Returns true if the edge existed.
+    #[wasm_bindgen(js_name = removeEdge)]
+    pub fn remove_edge(&mut self, from: usize, to: usize) -> bool {
+        let pos = match self
+            .adj
+            .get(from)
+            .and_then(|succs| succs.iter().position(|&w| w == to))
+        {
+            Some(p) => p,
+            None => return false,
+        };
+        self.adj[from].remove(pos);
+        if let Some(p) = self.rev_adj[to].iter().position(|&u| u == from) {
+            self.rev_adj[to].remove(p);
+        }
+        self.edge_count -= 1;
+        true
+    }
+
     /// Number of nodes.
     #[wasm_bindgen(js_name = nodeCount)]
     pub fn node_count(&self) -> usize {
@@ -185,6 +204,32 @@ impl DiGraph {
         Ok(graph)
     }
 
+    /// Content fingerprint for cache keys: 64-bit FNV-1a over node IDs and
+    /// edges in index order, as 16 hex digits. Graphs with equal toJson
+    /// snapshots (and so equal metric results) share a fingerprint.
+    pub fn fingerprint(&self) -> String {
+        const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+        let mut hash = OFFSET;
+        let mut feed = |bytes: &[u8]| {
+            for &b in bytes {
+                hash ^= b as u64;
+                hash = hash.wrapping_mul(PRIME);
+            }
+        };
+        for id in &self.nodes {
+            feed(id.as_bytes());
+            // 0xff never occurs in UTF-8, so IDs cannot run together
+            feed(&[0xff]);
+        }
+        feed(&[0xfe]);
+        for (from, to) in self.edges() {
+            feed(&(from as u64).to_le_bytes());
+            feed(&(to as u64).to_le_bytes());
+        }
+        format!("{:016x}", hash)
+    }
+
     /// Get successors of a node as JSON array of indices.
     pub fn successors(&self, node: usize) -> JsValue {
         let succs = self.adj.get(node).map_or(&[][..], |v| v.as_slice());
@@ -232,6 +277,17 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
     }
 
+    /// Get all open nodes on the critical chains of remaining work (for "CP" badges).
+    /// Closed nodes are excluded before analysis, so results track issues closing.
+    /// closed_set is an array of bytes where non-zero means closed.
+    #[wasm_bindgen(js_name = criticalPathMembers)]
+    pub fn critical_path_members(&self, closed_set: &[u8]) -> JsValue {
+        use crate::algorithms::critical_path::critical_path_members;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let nodes = critical_path_members(self, &closed);
+        serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
+    }
+
     /// Get the maximum height (critical path length).
     #[wasm_bindgen(js_name = criticalPathLength)]
     pub fn critical_path_length(&self) -> f64 {
@@ -340,6 +396,32 @@ impl DiGraph {
         degeneracy(self)
     }
 
+    /// Nodes in the k-core (core number >= k), for the core-work lens.
+    /// Feed the result to `subgraph` to render only the densely connected core.
+    #[wasm_bindgen(js_name = kcoreNodes)]
+    pub fn kcore_nodes(&self, k: u32) -> JsValue {
+        use crate::algorithms::kcore::nodes_in_kcore;
+        let nodes = nodes_in_kcore(self, k);
+        serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
+    }
+
+    /// Detect communities (label propagation) for cluster hulls and list group headers.
+    /// names is an optional { key: name } map of stored cluster names; a cluster's key
+    /// is its smallest member ID, so names survive re-detection.
+    /// Returns JSON: { community: number[], clusters: [{key, name, nodes}] }
+    #[wasm_bindgen(js_name = communities)]
+    pub fn communities(&self, names: JsValue) -> Result<JsValue, JsError> {
+        use crate::algorithms::community::communities;
+        use std::collections::HashMap;
+        let names: HashMap<String, String> = if names.is_undefined() || names.is_null() {
+            HashMap::new()
+        } else {
+            serde_wasm_bindgen::from_value(names).map_err(|e| JsError::new(&e.to_string()))?
+        };
+        let result = communities(self, &names);
+        Ok(serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL))
+    }
+
     /// Find articulation points (cut vertices) in the graph.
     /// These are nodes whose removal disconnects the graph.
     /// Returns array of node indices.
@@ -360,6 +442,16 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&br).unwrap_or(JsValue::NULL)
     }
 
+    /// Cut-point badges and bridge edge styling for the graph view.
+    /// Returns JSON: { cut_point: bool[], bridges: [[from, to]], bridge_degree: number[] }
+    /// Bridges are oriented like the dependency edges they correspond to.
+    #[wasm_bindgen(js_name = structuralBadges)]
+    pub fn structural_badges(&self) -> JsValue {
+        use crate::algorithms::articulation::structural_badges;
+        let result = structural_badges(self);
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
     /// Find strongly connected components using Tarjan's algorithm.
     /// Returns JSON: { components: number[][], has_cycles: bool, cycle_count: number }
     #[wasm_bindgen(js_name = tarjanScc)]
@@ -405,6 +497,29 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
     }
 
+    /// Break cycles down per SCC with the suggested edges to cut inside each one.
+    /// Returns JSON: { components: [{nodes, ids, edges, suggestions}], total_cycles, truncated }
+    #[wasm_bindgen(js_name = cycleInspection)]
+    pub fn cycle_inspection(
+        &self,
+        suggestions_per_component: usize,
+        max_cycles_to_enumerate: usize,
+    ) -> JsValue {
+        use crate::algorithms::cycles::cycle_inspection;
+        let result = cycle_inspection(self, suggestions_per_component, max_cycles_to_enumerate);
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
+    /// Cycle membership for highlighting nodes and edges across views.
+    /// Returns JSON: { node_cycle: (number|null)[], edges: [[from, to]], cycles: number[][] }
+    /// `cycles` is in jump-to-next order; `node_cycle` indexes into it.
+    #[wasm_bindgen(js_name = cycleOverlay)]
+    pub fn cycle_overlay(&self) -> JsValue {
+        use crate::algorithms::cycles::cycle_overlay;
+        let result = cycle_overlay(self);
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
     /// Compute slack for each node in the DAG.
     /// Slack = critical_path_length - longest_path_through_node.
     /// Zero slack means the node is on the critical path.
@@ -423,6 +538,93 @@ impl DiGraph {
         total_float(self)
     }
 
+    /// Top-N lists for the metrics dashboard: PageRank, betweenness, in-degree, slack.
+    /// Returns JSON: { pagerank, betweenness, in_degree, slack } where each is [{node, id, score}]
+    #[wasm_bindgen(js_name = metricsDashboard)]
+    pub fn metrics_dashboard(&self, limit: usize) -> JsValue {
+        use crate::algorithms::ranking::metrics_dashboard;
+        let result = metrics_dashboard(self, limit);
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
+    /// Bin per-node scores (PageRank, slack, priority, computeMetric output, ...)
+    /// into up to `count` quantile buckets for node coloring with a legend.
+    /// NaN scores get no bucket. Returns JSON: { node_bucket, buckets: [{min, max, count}] }
+    #[wasm_bindgen(js_name = scoreBuckets)]
+    pub fn score_buckets(scores: &[f64], count: usize) -> JsValue {
+        use crate::algorithms::ranking::score_buckets;
+        let result = score_buckets(scores, count);
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
+    /// Order all nodes by a computed metric for list sorting.
+    /// metric is one of: pagerank, betweenness, slack, blocked_count, depth.
+    /// closed_set is an array of bytes where non-zero means closed.
+    #[wasm_bindgen(js_name = sortByMetric)]
+    pub fn sort_by_metric(
+        &self,
+        metric: &str,
+        closed_set: &[u8],
+        descending: bool,
+    ) -> Result<JsValue, JsError> {
+        use crate::algorithms::ranking::{sort_by_metric, Metric};
+        let metric = Metric::parse(metric)
+            .ok_or_else(|| JsError::new(&format!("unknown metric '{}'", metric)))?;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let order = sort_by_metric(self, metric, &closed, descending);
+        Ok(serde_wasm_bindgen::to_value(&order).unwrap_or(JsValue::NULL))
+    }
+
+    /// Evaluate a user-defined metric expression (e.g.
+    /// `pagerank * blocked_days + 2 * is_critical_path`) for every node.
+    /// values is an object mapping extra variable names to per-node number arrays.
+    /// closed_set is an array of bytes where non-zero means closed.
+    /// Returns JSON array of per-node results; comparisons yield 1 or 0 for filtering.
+    #[wasm_bindgen(js_name = computeMetric)]
+    pub fn compute_metric(
+        &self,
+        expr: &str,
+        closed_set: &[u8],
+        values: JsValue,
+    ) -> Result<JsValue, JsError> {
+        use crate::expr::{evaluate, Expr};
+        use std::collections::HashMap;
+        let expr = Expr::parse(expr).map_err(|e| JsError::new(&e.to_string()))?;
+        let values: HashMap<String, Vec<f64>> = if values.is_undefined() || values.is_null() {
+            HashMap::new()
+        } else {
+            serde_wasm_bindgen::from_value(values).map_err(|e| JsError::new(&e.to_string()))?
+        };
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let scores =
+            evaluate(self, &expr, &closed, &values).map_err(|e| JsError::new(&e.to_string()))?;
+        Ok(serde_wasm_bindgen::to_value(&scores).unwrap_or(JsValue::NULL))
+    }
+
+    /// Compute a CPM schedule from per-node durations (estimates).
+    /// Returns JSON: { entries: [{node, duration, earliest_start, earliest_finish,
+    /// latest_start, latest_finish, slack, critical}], project_duration, critical_path, is_dag }
+    /// Missing or invalid durations default to 1.
+    #[wasm_bindgen(js_name = cpmSchedule)]
+    pub fn cpm_schedule(&self, durations: &[f64]) -> JsValue {
+        use crate::algorithms::schedule::cpm_schedule;
+        let result = cpm_schedule(self, durations);
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
+    /// Estimate totals for open work: the overall sum and the sum along one
+    /// estimate-weighted critical chain. Missing estimates (NaN or negative)
+    /// count as 0 and are listed in `unestimated`.
+    /// Returns JSON: { open_estimate, critical_path_estimate, critical_path,
+    /// unestimated, is_dag }
+    #[wasm_bindgen(js_name = estimateTotals)]
+    pub fn estimate_totals(&self, closed_set: &[u8], estimates: &[f64]) -> JsValue {
+        use crate::algorithms::schedule::estimate_totals;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let result = estimate_totals(self, &closed, estimates);
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
     /// Compute coverage set (greedy vertex cover).
     /// Finds nodes that collectively "cover" all edges in the graph.
     /// Returns JSON: { items: [{node, edges_added}], edges_covered, total_edges, coverage_ratio }
@@ -506,6 +708,23 @@ impl DiGraph {
         extract_subgraph(self, indices)
     }
 
+    /// Source-graph index of each node of `subgraph(indices)`, in subgraph order
+    /// (input order without invalid or repeated indices).
+    #[wasm_bindgen(js_name = subgraphMapping)]
+    pub fn subgraph_mapping(&self, indices: &[usize]) -> JsValue {
+        use crate::algorithms::subgraph::subgraph_mapping;
+        let mapping = subgraph_mapping(self, indices);
+        serde_wasm_bindgen::to_value(&mapping).unwrap_or(JsValue::NULL)
+    }
+
+    /// Extract a node's dependency cone as a subgraph for a focused graph view.
+    /// Subgraph node i is dependencyCone(node)[i] in this graph.
+    #[wasm_bindgen(js_name = coneSubgraph)]
+    pub fn cone_subgraph(&self, node: usize) -> DiGraph {
+        use crate::algorithms::subgraph::cone_subgraph;
+        cone_subgraph(self, node)
+    }
+
     /// Get all node indices reachable from a source node (outgoing direction).
     #[wasm_bindgen(js_name = reachableFrom)]
     pub fn reachable_from(&self, source: usize) -> JsValue {
@@ -522,6 +741,41 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
     }
 
+    /// Shortest dependency path from source to target along edge direction.
+    /// Returns array of node indices including both endpoints, or null if unreachable.
+    #[wasm_bindgen(js_name = shortestPath)]
+    pub fn shortest_path(&self, source: usize, target: usize) -> JsValue {
+        use crate::reachability::shortest_path;
+        let result = shortest_path(self, source, target);
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
+    /// Dependency paths from source to target (the `limit` shortest, or longest with
+    /// `longest`) plus every node and edge between them for highlighting.
+    /// Longest-first returns no paths when the region between them is cyclic.
+    /// Returns JSON: { paths, truncated, nodes, edges: [[from, to]] }
+    #[wasm_bindgen(js_name = allPaths)]
+    pub fn all_paths(
+        &self,
+        source: usize,
+        target: usize,
+        limit: usize,
+        longest: Option<bool>,
+    ) -> JsValue {
+        use crate::reachability::all_paths;
+        let result = all_paths(self, source, target, limit, longest.unwrap_or(false));
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
+    /// Check whether adding the edge from -> to would create a dependency cycle.
+    /// Returns the would-be cycle as [from, to, ...] (null if the edge is safe to add).
+    #[wasm_bindgen(js_name = wouldCreateCycle)]
+    pub fn would_create_cycle(&self, from: usize, to: usize) -> JsValue {
+        use crate::reachability::would_create_cycle;
+        let result = would_create_cycle(self, from, to);
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
     /// Get all nodes in the dependency cone (ancestors + node + descendants).
     #[wasm_bindgen(js_name = dependencyCone)]
     pub fn dependency_cone(&self, node: usize) -> JsValue {
@@ -530,6 +784,52 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
     }
 
+    /// Layered layout (layers by dependency depth, barycenter ordering) for the
+    /// graph view and minimap. Returns JSON: { nodes: [{node, layer, position, x, y}],
+    /// layers, width } with unit coordinates the view scales to its viewport.
+    #[wasm_bindgen(js_name = layeredLayout)]
+    pub fn layered_layout(&self) -> JsValue {
+        use crate::layout::layered_layout;
+        let result = layered_layout(self);
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
+    /// Visible neighborhood of a focus node for incremental graph browsing.
+    /// expand_up/expand_down list nodes whose blockers/dependents are expanded.
+    /// Returns JSON: { nodes: [{node, id, hidden_blockers, hidden_dependents,
+    /// expanded_up, expanded_down}], edges: [[from, to]] }
+    #[wasm_bindgen(js_name = neighborhood)]
+    pub fn neighborhood(
+        &self,
+        focus: usize,
+        expand_up: &[usize],
+        expand_down: &[usize],
+    ) -> JsValue {
+        use crate::neighborhood::neighborhood;
+        let result = neighborhood(self, focus, expand_up, expand_down);
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
+    /// Reduced graph for the view with hidden nodes removed and groups collapsed.
+    /// groups is an array of { label, nodes }; hidden_subtrees (optional) hides each
+    /// root together with all of its transitive dependents.
+    /// Returns JSON: { nodes: [{id, group, members}], edges: [[from, to]] } where
+    /// edges index into nodes.
+    #[wasm_bindgen(js_name = collapseView)]
+    pub fn collapse_view(
+        &self,
+        hidden: &[usize],
+        groups: JsValue,
+        hidden_subtrees: Option<Vec<usize>>,
+    ) -> Result<JsValue, JsError> {
+        use crate::collapse::{collapse_view, NodeGroup};
+        let groups: Vec<NodeGroup> =
+            serde_wasm_bindgen::from_value(groups).map_err(|e| JsError::new(&e.to_string()))?;
+        let subtrees = hidden_subtrees.unwrap_or_default();
+        let result = collapse_view(self, hidden, &subtrees, &groups);
+        Ok(serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL))
+    }
+
     // ========================================================================
     // Actionable queries (work with closed_set to determine workable items)
     // ========================================================================
@@ -581,6 +881,82 @@ impl DiGraph {
         open_blocker_count(self, node, &closed)
     }
 
+    /// Walk the transitive open blockers of a node level by level.
+    /// closed_set is an array of bytes where non-zero means closed.
+    /// Returns JSON: { levels: number[][], roots: number[] } where roots are
+    /// blockers with no open blockers of their own (root causes of the stall).
+    #[wasm_bindgen(js_name = blockingChain)]
+    pub fn blocking_chain(&self, node: usize, closed_set: &[u8]) -> JsValue {
+        use crate::reachability::blocking_chain;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let result = blocking_chain(self, node, &closed);
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
+    /// Ready work: open issues with no open blockers, ranked by a blend of
+    /// priority, slack urgency, downstream impact (PageRank of dependents), and
+    /// the number of open issues downstream (unblocks_weight, 0 when omitted).
+    /// priorities holds per-node priority (0 = P0); missing entries default to P2.
+    /// Returns JSON array of { node, id, score, priority, urgency, impact, unblocks,
+    /// downstream }.
+    #[wasm_bindgen(js_name = readyWork)]
+    pub fn ready_work(
+        &self,
+        closed_set: &[u8],
+        priorities: &[f64],
+        priority_weight: f64,
+        slack_weight: f64,
+        impact_weight: f64,
+        unblocks_weight: Option<f64>,
+    ) -> JsValue {
+        use crate::algorithms::ready::{ready_work, ReadyWeights};
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let weights = ReadyWeights {
+            priority: priority_weight,
+            slack: slack_weight,
+            impact: impact_weight,
+            unblocks: unblocks_weight.unwrap_or(0.0),
+        };
+        let items = ready_work(self, &closed, priorities, weights);
+        serde_wasm_bindgen::to_value(&items).unwrap_or(JsValue::NULL)
+    }
+
+    /// Ready work with the default weights (priority 0.4, slack 0.2, impact 0.2,
+    /// unblocks 0.2): the ready view's default ordering.
+    #[wasm_bindgen(js_name = readyWorkDefault)]
+    pub fn ready_work_default(&self, closed_set: &[u8], priorities: &[f64]) -> JsValue {
+        use crate::algorithms::ready::{ready_work, ReadyWeights};
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let items = ready_work(self, &closed, priorities, ReadyWeights::default());
+        serde_wasm_bindgen::to_value(&items).unwrap_or(JsValue::NULL)
+    }
+
+    /// Status-bar health summary: open/blocked/ready counts, cycle count,
+    /// remaining critical path length, and density.
+    /// Returns JSON: { total, open, blocked, ready, cycle_count, critical_path_length,
+    /// orphans, density }
+    #[wasm_bindgen(js_name = healthSummary)]
+    pub fn health_summary(&self, closed_set: &[u8]) -> JsValue {
+        use crate::health::health_summary;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let result = health_summary(self, &closed);
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
+    /// Check health invariants for CI gating.
+    /// thresholds is { fail_on_cycles, max_critical_path, max_orphans, max_blocked },
+    /// all optional. Returns JSON: { passed, summary, violations: [{rule, limit,
+    /// actual, nodes}] }; a failed check should exit nonzero.
+    #[wasm_bindgen(js_name = checkHealth)]
+    pub fn check_health(&self, closed_set: &[u8], thresholds: JsValue) -> Result<JsValue, JsError> {
+        use crate::health::{check_health, HealthThresholds};
+        let thresholds: HealthThresholds =
+            serde_wasm_bindgen::from_value(thresholds).map_err(|e| JsError::new(&e.to_string()))?;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let result = check_health(self, &closed, &thresholds);
+        Ok(serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL))
+    }
+
     // ========================================================================
     // What-If simulation (cascade impact analysis)
     // ========================================================================
@@ -628,6 +1004,38 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&results).unwrap_or(JsValue::NULL)
     }
 
+    /// Preview the impact of closing or deleting a node: cascade unblocks,
+    /// whether the remaining critical path shortens, and whether deletion
+    /// would disconnect its component.
+    /// Returns JSON: { node, what_if, critical_path_before, critical_path_after,
+    /// shortens_critical_path, disconnects }
+    #[wasm_bindgen(js_name = closeImpact)]
+    pub fn close_impact(&self, node: usize, closed_set: &[u8]) -> JsValue {
+        use crate::whatif::close_impact;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let result = close_impact(self, node, &closed);
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
+    /// Sandbox what-if: evaluate hypothetical closes and dependency edits without
+    /// changing the graph. scenario is { close: number[], add_edges: [[from, to]],
+    /// remove_edges: [[from, to]] }; every field is optional.
+    /// Returns JSON: { critical_path_before, critical_path_after, critical_path, ready,
+    /// newly_ready, newly_blocked, blocked_before, blocked_after, has_cycles }
+    #[wasm_bindgen(js_name = evaluateScenario)]
+    pub fn evaluate_scenario(
+        &self,
+        closed_set: &[u8],
+        scenario: JsValue,
+    ) -> Result<JsValue, JsError> {
+        use crate::whatif::{evaluate_scenario, Scenario};
+        let scenario: Scenario =
+            serde_wasm_bindgen::from_value(scenario).map_err(|e| JsError::new(&e.to_string()))?;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let result = evaluate_scenario(self, &closed, &scenario);
+        Ok(serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL))
+    }
+
     // ========================================================================
     // TopK Set (greedy submodular selection for maximum unlock)
     // ========================================================================
@@ -653,6 +1061,339 @@ impl DiGraph {
         let result = topk_set_default(self, &closed);
         serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
     }
+
+    // ========================================================================
+    // Search
+    // ========================================================================
+
+    /// Fuzzy-find issues by ID and title (fzf-style subsequence scoring).
+    /// titles is an array of per-node titles (may be empty to match IDs only).
+    /// Returns JSON array of { node, id, score, field, positions } sorted by score.
+    #[wasm_bindgen(js_name = fuzzyFind)]
+    pub fn fuzzy_find(&self, query: &str, titles: Vec<String>, limit: usize) -> JsValue {
+        use crate::fuzzy::fuzzy_find;
+        let matches = fuzzy_find(self, query, &titles, limit);
+        serde_wasm_bindgen::to_value(&matches).unwrap_or(JsValue::NULL)
+    }
+
+    /// Evaluate a filter expression (e.g. `status:open label:auth blocked:true`)
+    /// against per-node issue records and return the matching node indices.
+    /// records is an array of { title, status, priority, issue_type, assignee, labels,
+    /// created_at, updated_at, estimate } parallel to node indices; `me` resolves
+    /// `assignee:me`.
+    /// `cutpoint:true` keeps structurally critical issues (articulation points);
+    /// `core:>=k` keeps the k-core; `slack:<3d` keeps open work close to the critical
+    /// path, using the optional per-node durations (days, default 1).
+    #[wasm_bindgen(js_name = filterNodes)]
+    pub fn filter_nodes(
+        &self,
+        query: &str,
+        records: JsValue,
+        me: Option<String>,
+        durations: Option<Vec<f64>>,
+    ) -> Result<JsValue, JsError> {
+        use crate::filter::{filter_nodes, Filter};
+        use crate::records::load_records;
+        let filter = Filter::parse(query).map_err(|e| JsError::new(&e.to_string()))?;
+        let values: Vec<serde_json::Value> =
+            serde_wasm_bindgen::from_value(records).map_err(|e| JsError::new(&e.to_string()))?;
+        let records = load_records(&values).records;
+        let durations = durations.unwrap_or_default();
+        let nodes = filter_nodes(self, &filter, &records, me.as_deref(), &durations);
+        Ok(serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL))
+    }
+
+    /// Diagnostics for issue records: what the lenient loader used by filterNodes,
+    /// diffFrom and markdownReport repaired (old field names, wrong value types,
+    /// non-object records). Unknown fields are ignored without a warning.
+    /// Returns JSON array of { index, field, message }.
+    #[wasm_bindgen(js_name = recordWarnings)]
+    pub fn record_warnings(records: JsValue) -> Result<JsValue, JsError> {
+        use crate::records::load_records;
+        let values: Vec<serde_json::Value> =
+            serde_wasm_bindgen::from_value(records).map_err(|e| JsError::new(&e.to_string()))?;
+        let warnings = load_records(&values).warnings;
+        Ok(serde_wasm_bindgen::to_value(&warnings).unwrap_or(JsValue::NULL))
+    }
+
+    /// Group nodes by key (epic, label, assignee, status) with per-group aggregates.
+    /// keys is an array parallel to node indices whose entries are a string or an
+    /// array of strings; order is the current list order (empty for index order).
+    /// closed_set is an array of bytes where non-zero means closed.
+    /// Returns JSON array of { key, nodes, open, closed, estimate_total, estimate_open }.
+    #[wasm_bindgen(js_name = groupBy)]
+    pub fn group_by(
+        &self,
+        keys: JsValue,
+        order: &[usize],
+        closed_set: &[u8],
+        estimates: &[f64],
+    ) -> Result<JsValue, JsError> {
+        use crate::grouping::{group_by, GroupKey};
+        let keys: Vec<GroupKey> =
+            serde_wasm_bindgen::from_value(keys).map_err(|e| JsError::new(&e.to_string()))?;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let groups = group_by(self, &keys, order, &closed, estimates);
+        Ok(serde_wasm_bindgen::to_value(&groups).unwrap_or(JsValue::NULL))
+    }
+
+    /// Label coupling: co-occurrence and cross-label dependency edge counts.
+    /// labels is an array parallel to node indices of string arrays.
+    /// Returns JSON: { labels, counts, pairs: [{a, b, co_occurrence, cross_edges}] }
+    #[wasm_bindgen(js_name = labelCoupling)]
+    pub fn label_coupling(&self, labels: JsValue) -> Result<JsValue, JsError> {
+        use crate::labels::label_coupling;
+        let labels: Vec<Vec<String>> =
+            serde_wasm_bindgen::from_value(labels).map_err(|e| JsError::new(&e.to_string()))?;
+        let result = label_coupling(self, &labels);
+        Ok(serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL))
+    }
+
+    // ========================================================================
+    // Hierarchy (parent-child rollups for epics and subtasks)
+    // ========================================================================
+
+    /// Build the parent-child tree and roll up descendant counts and estimates.
+    /// parents holds the parent index per node, negative for top-level nodes.
+    /// closed_set is an array of bytes where non-zero means closed.
+    /// Returns JSON: { nodes: [{node, parent, depth, children, descendants_open,
+    /// descendants_closed, subtree_estimate, subtree_estimate_closed, progress,
+    /// progress_estimate}], roots, order }
+    #[wasm_bindgen(js_name = hierarchyRollup)]
+    pub fn hierarchy_rollup(
+        &self,
+        parents: &[i32],
+        closed_set: &[u8],
+        estimates: &[f64],
+    ) -> JsValue {
+        use crate::hierarchy::{hierarchy_rollup, parents_from_i32};
+        let parents = parents_from_i32(parents);
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let result = hierarchy_rollup(self, &parents, &closed, estimates);
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
+    /// Plan a clone of an issue or epic with its whole subtree and dependencies.
+    /// parents holds the parent index per node, negative for top-level nodes;
+    /// every occurrence of `find` in the titles is replaced by `replace`.
+    /// Returns JSON: { items: [{source, title, parent_item, parent_node,
+    /// blocked_by_items, blocked_by_nodes}] } in creation order.
+    #[wasm_bindgen(js_name = clonePlan)]
+    pub fn clone_plan(
+        &self,
+        root: usize,
+        parents: &[i32],
+        titles: Vec<String>,
+        find: &str,
+        replace: &str,
+    ) -> JsValue {
+        use crate::hierarchy::{clone_plan, parents_from_i32};
+        let parents = parents_from_i32(parents);
+        let result = clone_plan(self, root, &parents, &titles, find, replace);
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
+    // ========================================================================
+    // Reload (carry view state across data refreshes)
+    // ========================================================================
+
+    /// Map indices of a previously loaded graph onto this one by node ID.
+    /// Returns JSON: { mapping: (number|null)[], added, removed, structure_changed }
+    /// Only added/removed issues and edges count; use diffFrom for field edits.
+    #[wasm_bindgen(js_name = remapFrom)]
+    pub fn remap_from(&self, previous: &DiGraph) -> JsValue {
+        use crate::remap::remap_indices;
+        let result = remap_indices(previous, self);
+        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+    }
+
+    /// Carry a selection from a previously loaded graph onto this one.
+    /// Falls back to the nearest surviving node if the selected one was removed.
+    #[wasm_bindgen(js_name = remapSelection)]
+    pub fn remap_selection(&self, previous: &DiGraph, selected: usize) -> Option<usize> {
+        use crate::remap::{remap_indices, remap_selection};
+        remap_selection(&remap_indices(previous, self), selected)
+    }
+
+    /// Issue-level diff from a base snapshot to this graph (e.g. two Dolt commits).
+    /// Records are arrays parallel to each graph's nodes (same shape as filterNodes);
+    /// pass empty arrays to diff only issues and edges.
+    /// Returns JSON: { added, removed, changed: [{id, fields: [{field, old, new}]}],
+    /// edges_added, edges_removed } with IDs in place of indices.
+    #[wasm_bindgen(js_name = diffFrom)]
+    pub fn diff_from(
+        &self,
+        base: &DiGraph,
+        base_records: JsValue,
+        records: JsValue,
+    ) -> Result<JsValue, JsError> {
+        use crate::diff::snapshot_diff;
+        use crate::records::load_records;
+        let base_values: Vec<serde_json::Value> = serde_wasm_bindgen::from_value(base_records)
+            .map_err(|e| JsError::new(&e.to_string()))?;
+        let values: Vec<serde_json::Value> =
+            serde_wasm_bindgen::from_value(records).map_err(|e| JsError::new(&e.to_string()))?;
+        let base_records = load_records(&base_values).records;
+        let records = load_records(&values).records;
+        let diff = snapshot_diff(base, &base_records, self, &records);
+        Ok(serde_wasm_bindgen::to_value(&diff).unwrap_or(JsValue::NULL))
+    }
+
+    // ========================================================================
+    // Export (analytic records and diagram sources)
+    // ========================================================================
+
+    /// Per-issue computed metrics in the flattened analytic schema.
+    /// closed_set is an array of bytes where non-zero means closed.
+    /// Returns JSON array of { id, blockers, dependents, blocks_open, pagerank,
+    /// betweenness, slack, depth, core } in node index order.
+    #[wasm_bindgen(js_name = analyticRecords)]
+    pub fn analytic_records(&self, closed_set: &[u8]) -> JsValue {
+        use crate::export::analytic::analytic_records;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let records = analytic_records(self, &closed);
+        serde_wasm_bindgen::to_value(&records).unwrap_or(JsValue::NULL)
+    }
+
+    /// Analytic records serialized as JSONL, one issue per line.
+    #[wasm_bindgen(js_name = analyticJsonl)]
+    pub fn analytic_jsonl(&self, closed_set: &[u8]) -> String {
+        use crate::export::analytic::{analytic_records, to_jsonl};
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        to_jsonl(&analytic_records(self, &closed))
+    }
+
+    /// Render the graph as Graphviz DOT, colored by status (closed, blocked, ready)
+    /// with critical-path edges emphasized. titles is optional (may be empty).
+    #[wasm_bindgen(js_name = toDot)]
+    pub fn to_dot(&self, closed_set: &[u8], titles: Vec<String>) -> String {
+        use crate::export::dot::to_dot;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        to_dot(self, &closed, &titles)
+    }
+
+    /// Render the graph as DOT with Graphviz clusters (e.g. by epic, label, or assignee).
+    /// clusters holds the cluster key per node; empty strings leave the node unclustered.
+    #[wasm_bindgen(js_name = toDotClustered)]
+    pub fn to_dot_clustered(
+        &self,
+        closed_set: &[u8],
+        titles: Vec<String>,
+        clusters: Vec<String>,
+    ) -> String {
+        use crate::export::dot::to_dot_clustered;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        to_dot_clustered(self, &closed, &titles, &clusters)
+    }
+
+    /// Markdown status report since a cutoff date: closed/opened/newly blocked issues,
+    /// critical path, top risks by betweenness, and cycle warnings.
+    /// records is an array parallel to node indices (same shape as filterNodes).
+    #[wasm_bindgen(js_name = markdownReport)]
+    pub fn markdown_report(
+        &self,
+        records: JsValue,
+        since: &str,
+        limit: usize,
+    ) -> Result<String, JsError> {
+        use crate::export::report::markdown_report;
+        use crate::records::load_records;
+        let values: Vec<serde_json::Value> =
+            serde_wasm_bindgen::from_value(records).map_err(|e| JsError::new(&e.to_string()))?;
+        let records = load_records(&values).records;
+        Ok(markdown_report(self, &records, since, limit))
+    }
+
+    /// Monte Carlo forecast of when the open work lands, from weekly throughput over
+    /// the last history_weeks before as_of (YYYY-MM-DD), floored at the remaining
+    /// critical path (durations in days). With points, work is summed from record
+    /// estimates instead of counted. seed makes the result reproducible.
+    /// Returns JSON: { throughput, remaining, critical_path_days,
+    /// percentiles: [{percentile, days, date}] } for the 50th, 85th and 95th percentile.
+    #[wasm_bindgen(js_name = forecast)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn forecast(
+        &self,
+        records: JsValue,
+        durations: &[f64],
+        as_of: &str,
+        history_weeks: usize,
+        points: bool,
+        trials: usize,
+        seed: Option<u32>,
+    ) -> Result<JsValue, JsError> {
+        use crate::forecast::forecast;
+        use crate::records::load_records;
+        let values: Vec<serde_json::Value> =
+            serde_wasm_bindgen::from_value(records).map_err(|e| JsError::new(&e.to_string()))?;
+        let records = load_records(&values).records;
+        let seed = seed.map(u64::from);
+        let result = forecast(
+            self,
+            &records,
+            durations,
+            as_of,
+            history_weeks,
+            points,
+            trials,
+            seed,
+        )
+        .ok_or_else(|| JsError::new(&format!("invalid date '{}'", as_of)))?;
+        Ok(serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL))
+    }
+
+    /// Render due dates and projected critical-path finish dates as an .ics calendar.
+    /// durations are in days; due_dates are YYYY-MM-DD (empty = none); start_date is YYYY-MM-DD.
+    #[wasm_bindgen(js_name = toIcal)]
+    pub fn to_ical(
+        &self,
+        durations: &[f64],
+        titles: Vec<String>,
+        due_dates: Vec<String>,
+        start_date: &str,
+    ) -> String {
+        use crate::export::ical::to_ical;
+        to_ical(self, durations, &titles, &due_dates, start_date)
+    }
+
+    /// Render the parent-child hierarchy as an Org-mode outline of TODO/DONE entries.
+    /// parents holds the parent index per node, negative for top-level nodes.
+    #[wasm_bindgen(js_name = toOrg)]
+    pub fn to_org(
+        &self,
+        parents: &[i32],
+        closed_set: &[u8],
+        titles: Vec<String>,
+        estimates: &[f64],
+    ) -> String {
+        use crate::export::org::to_org;
+        use crate::hierarchy::parents_from_i32;
+        let parents = parents_from_i32(parents);
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        to_org(self, &parents, &closed, &titles, estimates)
+    }
+
+    /// Render the graph as a Mermaid `graph TD` flowchart for markdown docs.
+    /// titles is optional (may be empty).
+    #[wasm_bindgen(js_name = toMermaid)]
+    pub fn to_mermaid(&self, closed_set: &[u8], titles: Vec<String>) -> String {
+        use crate::export::mermaid::to_mermaid_flowchart;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        to_mermaid_flowchart(self, &closed, &titles)
+    }
+
+    /// Render the CPM schedule as a Mermaid `gantt` chart.
+    /// durations are in days; start_date is YYYY-MM-DD.
+    #[wasm_bindgen(js_name = toMermaidGantt)]
+    pub fn to_mermaid_gantt(
+        &self,
+        durations: &[f64],
+        titles: Vec<String>,
+        start_date: &str,
+    ) -> String {
+        use crate::export::mermaid::to_mermaid_gantt;
+        to_mermaid_gantt(self, durations, &titles, start_date)
+    }
 }
 
 // Internal methods (not exposed to WASM)
@@ -709,6 +1450,26 @@ mod tests {
         assert_eq!(g.edge_count(), 0);
     }
 
+    #[test]
+    fn test_fingerprint() {
+        let mut g = DiGraph::new();
+        g.add_node("a");
+        g.add_node("b");
+        let empty = DiGraph::new().fingerprint();
+        let nodes_only = g.fingerprint();
+        assert_eq!(nodes_only.len(), 16);
+        assert_ne!(empty, nodes_only);
+        g.add_edge(0, 1);
+        let with_edge = g.fingerprint();
+        assert_ne!(nodes_only, with_edge);
+        let copy = DiGraph::from_json(&g.to_json()).ok().unwrap();
+        assert_eq!(copy.fingerprint(), with_edge);
+        // IDs are delimited: ["ab"] differs from ["a", "b"]
+        let mut joined = DiGraph::new();
+        joined.add_node("ab");
+        assert_ne!(joined.fingerprint(), nodes_only);
+    }
+
     #[test]
     fn test_add_node_idempotent() {
         let mut g = DiGraph::new();
@@ -728,6 +1489,25 @@ mod tests {
         assert_eq!(g.edge_count(), 1);
     }
 
+    #[test]
+    fn test_remove_edge() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(a, c);
+
+        assert!(g.remove_edge(a, b));
+        assert!(!g.remove_edge(a, b)); // Already gone
+        assert!(!g.remove_edge(b, a)); // Never existed
+        assert!(!g.remove_edge(a, 99)); // Out of range
+        assert_eq!(g.edge_count(), 1);
+        assert_eq!(g.out_degree(a), 1);
+        assert_eq!(g.in_degree(b), 0);
+        assert_eq!(g.in_degree(c), 1);
+    }
+
     #[test]
     fn test_degrees() {
         let mut g = DiGraph::new();
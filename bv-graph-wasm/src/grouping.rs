@@ -0,0 +1,197 @@
+//! Group-by aggregation for the issue list.
+//!
+//! Buckets nodes by an arbitrary key (epic, label, assignee, status) and
+//! aggregates counts and estimates per bucket so the list can render
+//! collapsible group headers. Multi-valued keys such as labels place a
+//! node in every matching group.
+
+use crate::graph::DiGraph;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Group name used for nodes without any key.
+pub const UNGROUPED: &str = "(none)";
+
+/// Grouping key for one node: a single value or several (e.g. labels).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum GroupKey {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl GroupKey {
+    /// Non-empty key values with duplicates removed.
+    fn values(&self) -> Vec<&str> {
+        let all: Vec<&str> = match self {
+            GroupKey::One(k) => vec![k.as_str()],
+            GroupKey::Many(ks) => ks.iter().map(String::as_str).collect(),
+        };
+        let mut values: Vec<&str> = Vec::with_capacity(all.len());
+        for k in all {
+            if !k.is_empty() && !values.contains(&k) {
+                values.push(k);
+            }
+        }
+        values
+    }
+}
+
+/// One group header with its members and aggregates.
+#[derive(Debug, Clone, Serialize)]
+pub struct Group {
+    /// Group key (UNGROUPED for nodes without a key)
+    pub key: String,
+    /// Member node indices in list order
+    pub nodes: Vec<usize>,
+    /// Number of open members
+    pub open: usize,
+    /// Number of closed members
+    pub closed: usize,
+    /// Sum of member estimates
+    pub estimate_total: f64,
+    /// Sum of estimates over open members (remaining work)
+    pub estimate_open: f64,
+}
+
+/// Group nodes by key and aggregate per group.
+///
+/// Groups are sorted by key with UNGROUPED last. Within a group, members
+/// keep the relative order of `order` (the list's current sort); an empty
+/// `order` means node index order.
+///
+/// # Arguments
+/// * `graph` - The graph (provides node count)
+/// * `keys` - Grouping key per node (missing entries are ungrouped)
+/// * `order` - Current list order of node indices (may be empty)
+/// * `closed_set` - Boolean array indicating which nodes are closed
+/// * `estimates` - Per-node estimates (missing or invalid count as 0)
+pub fn group_by(
+    graph: &DiGraph,
+    keys: &[GroupKey],
+    order: &[usize],
+    closed_set: &[bool],
+    estimates: &[f64],
+) -> Vec<Group> {
+    let n = graph.len();
+    let order: Vec<usize> = if order.is_empty() {
+        (0..n).collect()
+    } else {
+        order.iter().copied().filter(|&v| v < n).collect()
+    };
+
+    let mut groups: BTreeMap<String, Group> = BTreeMap::new();
+    let mut ungrouped: Option<Group> = None;
+
+    for v in order {
+        let values = keys.get(v).map(GroupKey::values).unwrap_or_default();
+        let is_closed = closed_set.get(v).copied().unwrap_or(false);
+        let estimate = estimates
+            .get(v)
+            .copied()
+            .filter(|e| e.is_finite() && *e >= 0.0)
+            .unwrap_or(0.0);
+
+        let add = |group: &mut Group| {
+            group.nodes.push(v);
+            group.estimate_total += estimate;
+            if is_closed {
+                group.closed += 1;
+            } else {
+                group.open += 1;
+                group.estimate_open += estimate;
+            }
+        };
+
+        if values.is_empty() {
+            add(ungrouped.get_or_insert_with(|| empty_group(UNGROUPED)));
+        } else {
+            for key in values {
+                add(groups
+                    .entry(key.to_string())
+                    .or_insert_with(|| empty_group(key)));
+            }
+        }
+    }
+
+    groups.into_values().chain(ungrouped).collect()
+}
+
+fn empty_group(key: &str) -> Group {
+    Group {
+        key: key.to_string(),
+        nodes: Vec::new(),
+        open: 0,
+        closed: 0,
+        estimate_total: 0.0,
+        estimate_open: 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph_with(n: usize) -> DiGraph {
+        let mut graph = DiGraph::new();
+        for i in 0..n {
+            graph.add_node(&format!("n{}", i));
+        }
+        graph
+    }
+
+    fn one(k: &str) -> GroupKey {
+        GroupKey::One(k.to_string())
+    }
+
+    #[test]
+    fn test_group_by_single_key() {
+        let graph = graph_with(4);
+        let keys = vec![one("open"), one("closed"), one("open"), one("")];
+        let closed = vec![false, true, false, false];
+        let estimates = vec![1.0, 2.0, 3.0, 4.0];
+
+        let groups = group_by(&graph, &keys, &[], &closed, &estimates);
+        let names: Vec<&str> = groups.iter().map(|g| g.key.as_str()).collect();
+        assert_eq!(names, vec!["closed", "open", UNGROUPED]);
+
+        let open = &groups[1];
+        assert_eq!(open.nodes, vec![0, 2]);
+        assert_eq!(open.open, 2);
+        assert_eq!(open.estimate_total, 4.0);
+
+        let closed_group = &groups[0];
+        assert_eq!(closed_group.closed, 1);
+        assert_eq!(closed_group.estimate_open, 0.0);
+    }
+
+    #[test]
+    fn test_group_by_multi_valued_keys() {
+        let graph = graph_with(3);
+        let keys = vec![
+            GroupKey::Many(vec!["auth".to_string(), "ui".to_string()]),
+            GroupKey::Many(vec!["ui".to_string()]),
+            GroupKey::Many(vec![]),
+        ];
+        let groups = group_by(&graph, &keys, &[], &[], &[]);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0].key, "auth");
+        assert_eq!(groups[1].nodes, vec![0, 1]);
+        assert_eq!(groups[2].nodes, vec![2]);
+    }
+
+    #[test]
+    fn test_group_by_preserves_list_order() {
+        let graph = graph_with(3);
+        let keys = vec![one("a"), one("a"), one("a")];
+        let groups = group_by(&graph, &keys, &[2, 0, 1], &[], &[]);
+        assert_eq!(groups[0].nodes, vec![2, 0, 1]);
+    }
+
+    #[test]
+    fn test_group_key_deserialize() {
+        let keys: Vec<GroupKey> = serde_json::from_str(r#"["epic-1", ["a", "b"]]"#).unwrap();
+        assert_eq!(keys[0].values(), vec!["epic-1"]);
+        assert_eq!(keys[1].values(), vec!["a", "b"]);
+    }
+}
@@ -0,0 +1,317 @@
+//! Project health summary for the status bar.
+//!
+//! Collects the handful of numbers a status line shows at a glance (open,
+//! blocked, ready, cycles, remaining critical path, density) in one pass so
+//! the view can refresh them on every data change without calling several
+//! analyses separately. The same numbers back threshold checks for CI,
+//! which fail when a project-health invariant is violated.
+
+use crate::algorithms::critical_path::open_critical_path_length;
+use crate::algorithms::cycles::{is_cycle_component, tarjan_scc};
+use crate::algorithms::subgraph::extract_subgraph;
+use crate::graph::DiGraph;
+use crate::reachability::open_blocker_count;
+use serde::{Deserialize, Serialize};
+
+/// Live health indicators for a project.
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthSummary {
+    /// Total number of issues
+    pub total: usize,
+    /// Issues not in the closed set
+    pub open: usize,
+    /// Open issues with at least one open blocker
+    pub blocked: usize,
+    /// Open issues with no open blockers
+    pub ready: usize,
+    /// Number of dependency cycles (SCCs with a cycle, self-loops included)
+    /// in the whole graph
+    pub cycle_count: usize,
+    /// Longest chain of remaining (open) work; 0 if open work is cyclic
+    pub critical_path_length: f64,
+    /// Open issues with no blockers and no dependents
+    pub orphans: usize,
+    /// Graph density: edges / (nodes * (nodes - 1))
+    pub density: f64,
+}
+
+/// Compute the health summary.
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `closed_set` - Boolean array indicating which nodes are closed
+pub fn health_summary(graph: &DiGraph, closed_set: &[bool]) -> HealthSummary {
+    let is_closed = |v: usize| closed_set.get(v).copied().unwrap_or(false);
+    let open: Vec<usize> = (0..graph.len()).filter(|&v| !is_closed(v)).collect();
+    let blocked = open
+        .iter()
+        .filter(|&&v| open_blocker_count(graph, v, closed_set) > 0)
+        .count();
+    let orphans = open.iter().filter(|&&v| is_orphan(graph, v)).count();
+
+    HealthSummary {
+        total: graph.len(),
+        open: open.len(),
+        blocked,
+        ready: open.len() - blocked,
+        cycle_count: tarjan_scc(graph)
+            .components
+            .iter()
+            .filter(|c| is_cycle_component(graph, c))
+            .count(),
+        critical_path_length: open_critical_path_length(graph, closed_set),
+        orphans,
+        density: graph.density(),
+    }
+}
+
+fn is_orphan(graph: &DiGraph, v: usize) -> bool {
+    graph.in_degree(v) == 0 && graph.out_degree(v) == 0
+}
+
+/// Sorted members of every cycle (self-loops included).
+fn cycle_members(graph: &DiGraph) -> Vec<usize> {
+    let mut members: Vec<usize> = tarjan_scc(graph)
+        .components
+        .into_iter()
+        .filter(|c| is_cycle_component(graph, c))
+        .flatten()
+        .collect();
+    members.sort_unstable();
+    members
+}
+
+/// Limits checked by `check_health`; unset limits are not checked.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct HealthThresholds {
+    /// Fail if the graph contains any dependency cycle
+    pub fail_on_cycles: bool,
+    /// Maximum remaining critical path length
+    pub max_critical_path: Option<f64>,
+    /// Maximum number of orphan issues
+    pub max_orphans: Option<usize>,
+    /// Maximum number of blocked issues
+    pub max_blocked: Option<usize>,
+}
+
+/// A violated threshold.
+#[derive(Debug, Clone, Serialize)]
+pub struct Violation {
+    /// Rule name (`cycles`, `critical_path`, `orphans`, `blocked`)
+    pub rule: &'static str,
+    /// Configured limit (0 for `cycles`)
+    pub limit: f64,
+    /// Measured value; NaN when it could not be measured (the critical
+    /// path of cyclic open work)
+    pub actual: f64,
+    /// Offending issue IDs, where the rule has them (cycle members, orphans)
+    pub nodes: Vec<String>,
+}
+
+/// Result of a health check.
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthCheck {
+    /// Whether every checked threshold holds (the CI exit status)
+    pub passed: bool,
+    /// Measured values
+    pub summary: HealthSummary,
+    /// Violated thresholds, in the field order of HealthThresholds
+    pub violations: Vec<Violation>,
+}
+
+/// Check the health summary against thresholds.
+///
+/// `max_critical_path` is violated when open work is cyclic, since the
+/// length cannot be measured; the violation lists the open cycle members.
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `closed_set` - Boolean array indicating which nodes are closed
+/// * `thresholds` - Limits to enforce
+pub fn check_health(
+    graph: &DiGraph,
+    closed_set: &[bool],
+    thresholds: &HealthThresholds,
+) -> HealthCheck {
+    let summary = health_summary(graph, closed_set);
+    let ids = |nodes: &mut dyn Iterator<Item = usize>| -> Vec<String> {
+        nodes.filter_map(|v| graph.node_id(v)).collect()
+    };
+    let mut violations = Vec::new();
+
+    if thresholds.fail_on_cycles && summary.cycle_count > 0 {
+        violations.push(Violation {
+            rule: "cycles",
+            limit: 0.0,
+            actual: summary.cycle_count as f64,
+            nodes: ids(&mut cycle_members(graph).into_iter()),
+        });
+    }
+    if let Some(limit) = thresholds.max_critical_path {
+        // A cyclic open subgraph has no critical path to compare, so the
+        // gate fails rather than passing on the reported 0
+        let open: Vec<usize> = (0..graph.len())
+            .filter(|&v| !closed_set.get(v).copied().unwrap_or(false))
+            .collect();
+        // extract_subgraph renumbers in input order, so sub index i == open[i]
+        let open_cycles = cycle_members(&extract_subgraph(graph, &open));
+        if !open_cycles.is_empty() {
+            violations.push(Violation {
+                rule: "critical_path",
+                limit,
+                actual: f64::NAN,
+                nodes: ids(&mut open_cycles.into_iter().map(|i| open[i])),
+            });
+        } else if summary.critical_path_length > limit {
+            violations.push(Violation {
+                rule: "critical_path",
+                limit,
+                actual: summary.critical_path_length,
+                nodes: Vec::new(),
+            });
+        }
+    }
+    if let Some(limit) = thresholds.max_orphans {
+        if summary.orphans > limit {
+            let is_closed = |v: usize| closed_set.get(v).copied().unwrap_or(false);
+            let mut orphans = (0..graph.len()).filter(|&v| !is_closed(v) && is_orphan(graph, v));
+            violations.push(Violation {
+                rule: "orphans",
+                limit: limit as f64,
+                actual: summary.orphans as f64,
+                nodes: ids(&mut orphans),
+            });
+        }
+    }
+    if let Some(limit) = thresholds.max_blocked {
+        if summary.blocked > limit {
+            violations.push(Violation {
+                rule: "blocked",
+                limit: limit as f64,
+                actual: summary.blocked as f64,
+                nodes: Vec::new(),
+            });
+        }
+    }
+
+    HealthCheck {
+        passed: violations.is_empty(),
+        summary,
+        violations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_health_empty() {
+        let graph = DiGraph::new();
+        let health = health_summary(&graph, &[]);
+        assert_eq!(health.total, 0);
+        assert_eq!(health.ready, 0);
+        assert_eq!(health.critical_path_length, 0.0);
+    }
+
+    #[test]
+    fn test_health_chain() {
+        // a -> b -> c, a closed
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let health = health_summary(&graph, &[true, false, false]);
+        assert_eq!(health.total, 3);
+        assert_eq!(health.open, 2);
+        assert_eq!(health.ready, 1); // b
+        assert_eq!(health.blocked, 1); // c
+        assert_eq!(health.cycle_count, 0);
+        assert_eq!(health.critical_path_length, 2.0);
+    }
+
+    #[test]
+    fn test_health_cycle() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        let health = health_summary(&graph, &[]);
+        assert_eq!(health.cycle_count, 1);
+        assert_eq!(health.blocked, 2);
+        assert_eq!(health.critical_path_length, 0.0);
+    }
+
+    #[test]
+    fn test_check_health_thresholds() {
+        // a <-> b cycle, c -> d chain, e orphan
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_node("e");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+        graph.add_edge(c, d);
+
+        let none = check_health(&graph, &[], &HealthThresholds::default());
+        assert!(none.passed);
+        assert_eq!(none.summary.orphans, 1);
+
+        let strict = HealthThresholds {
+            fail_on_cycles: true,
+            max_critical_path: Some(1.0),
+            max_orphans: Some(0),
+            max_blocked: Some(10),
+        };
+        // Open work is cyclic, so the critical path cannot be measured
+        let check = check_health(&graph, &[], &strict);
+        assert!(!check.passed);
+        let rules: Vec<&str> = check.violations.iter().map(|v| v.rule).collect();
+        assert_eq!(rules, vec!["cycles", "critical_path", "orphans"]);
+        assert_eq!(check.violations[0].nodes, vec!["a", "b"]);
+        assert!(check.violations[1].actual.is_nan());
+        assert_eq!(check.violations[1].nodes, vec!["a", "b"]);
+        assert_eq!(check.violations[2].nodes, vec!["e"]);
+
+        // Closing a leaves open work acyclic (c -> d is too long); closing e fixes orphans
+        let closed = vec![true, false, false, false, true];
+        let check = check_health(&graph, &closed, &strict);
+        let rules: Vec<&str> = check.violations.iter().map(|v| v.rule).collect();
+        assert_eq!(rules, vec!["cycles", "critical_path"]);
+        assert_eq!(check.violations[1].actual, 2.0);
+    }
+
+    #[test]
+    fn test_check_health_self_loop() {
+        // a -> a is a cycle even though its SCC has one member
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, a);
+        graph.add_edge(a, b);
+        assert_eq!(health_summary(&graph, &[]).cycle_count, 1);
+
+        let thresholds = HealthThresholds {
+            fail_on_cycles: true,
+            max_critical_path: Some(5.0),
+            ..Default::default()
+        };
+        let check = check_health(&graph, &[], &thresholds);
+        let rules: Vec<&str> = check.violations.iter().map(|v| v.rule).collect();
+        assert_eq!(rules, vec!["cycles", "critical_path"]);
+        assert_eq!(check.violations[0].nodes, vec!["a"]);
+
+        // Closing a clears the open cycle, but the graph still has one
+        let check = check_health(&graph, &[true, false], &thresholds);
+        let rules: Vec<&str> = check.violations.iter().map(|v| v.rule).collect();
+        assert_eq!(rules, vec!["cycles"]);
+    }
+}
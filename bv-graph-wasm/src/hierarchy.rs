@@ -0,0 +1,455 @@
+//! Parent-child hierarchy rollups (epics and subtasks).
+//!
+//! Hierarchy is separate from blocking dependencies: each node has at most
+//! one parent, given as a parallel array. Rollups aggregate open/closed
+//! counts and estimates over each node's descendants so a tree view can
+//! show per-epic totals without walking the subtree itself.
+//!
+//! The same tree drives structural cloning: copying an epic produces a
+//! plan for recreating its subtree with the dependencies between copies.
+
+use crate::graph::DiGraph;
+use serde::Serialize;
+
+/// Rolled-up data for a single node in the hierarchy.
+#[derive(Debug, Clone, Serialize)]
+pub struct TreeNode {
+    /// Node index in the graph
+    pub node: usize,
+    /// Parent node index (None for roots)
+    pub parent: Option<usize>,
+    /// Depth in the tree (roots are 0)
+    pub depth: usize,
+    /// Direct children in index order
+    pub children: Vec<usize>,
+    /// Number of open descendants (excluding the node itself)
+    pub descendants_open: usize,
+    /// Number of closed descendants (excluding the node itself)
+    pub descendants_closed: usize,
+    /// Sum of estimates over the node and all descendants
+    pub subtree_estimate: f64,
+    /// Sum of estimates over closed nodes in the subtree (node included)
+    pub subtree_estimate_closed: f64,
+    /// Fraction of descendants closed; own status (0 or 1) for leaves
+    pub progress: f64,
+    /// Estimate-weighted completion of the subtree; equals `progress` when
+    /// the subtree has no estimates
+    pub progress_estimate: f64,
+}
+
+/// Result of a hierarchy rollup.
+#[derive(Debug, Clone, Serialize)]
+pub struct HierarchyResult {
+    /// Per-node rollups in node index order
+    pub nodes: Vec<TreeNode>,
+    /// Root nodes (no parent, or parent link broken by a cycle)
+    pub roots: Vec<usize>,
+    /// Pre-order traversal of the whole forest (render order for a tree view)
+    pub order: Vec<usize>,
+}
+
+/// Convert a parent array using negative values for "no parent" into options.
+pub fn parents_from_i32(parents: &[i32]) -> Vec<Option<usize>> {
+    parents
+        .iter()
+        .map(|&p| if p < 0 { None } else { Some(p as usize) })
+        .collect()
+}
+
+/// Resolve a valid estimate for a node (missing or invalid counts as 0).
+fn node_estimate(estimates: &[f64], node: usize) -> f64 {
+    estimates
+        .get(node)
+        .copied()
+        .filter(|e| e.is_finite() && *e >= 0.0)
+        .unwrap_or(0.0)
+}
+
+/// Build the hierarchy and compute per-node rollups.
+///
+/// Parent links that point outside the graph, to the node itself, or that
+/// would close a loop are dropped and the node is treated as a root.
+///
+/// # Arguments
+/// * `graph` - The dependency graph (provides node count)
+/// * `parents` - Parent index per node (None for top-level nodes)
+/// * `closed_set` - Boolean array indicating which nodes are closed
+/// * `estimates` - Per-node estimates (missing entries count as 0)
+pub fn hierarchy_rollup(
+    graph: &DiGraph,
+    parents: &[Option<usize>],
+    closed_set: &[bool],
+    estimates: &[f64],
+) -> HierarchyResult {
+    let n = graph.len();
+    let parent = sanitize_parents(n, parents);
+
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut roots = Vec::new();
+    for (v, &p) in parent.iter().enumerate() {
+        match p {
+            Some(p) => children[p].push(v),
+            None => roots.push(v),
+        }
+    }
+
+    // Pre-order traversal with depths
+    let mut order = Vec::with_capacity(n);
+    let mut depth = vec![0usize; n];
+    let mut stack: Vec<usize> = roots.iter().rev().copied().collect();
+    while let Some(v) = stack.pop() {
+        order.push(v);
+        for &c in children[v].iter().rev() {
+            depth[c] = depth[v] + 1;
+            stack.push(c);
+        }
+    }
+
+    // Post-order accumulation (reverse pre-order visits children first)
+    let mut open = vec![0usize; n];
+    let mut closed = vec![0usize; n];
+    let is_closed = |v: usize| closed_set.get(v).copied().unwrap_or(false);
+    let mut estimate: Vec<f64> = (0..n).map(|v| node_estimate(estimates, v)).collect();
+    let mut estimate_closed: Vec<f64> = (0..n)
+        .map(|v| if is_closed(v) { estimate[v] } else { 0.0 })
+        .collect();
+    for &v in order.iter().rev() {
+        if let Some(p) = parent[v] {
+            if is_closed(v) {
+                closed[p] += 1;
+            } else {
+                open[p] += 1;
+            }
+            open[p] += open[v];
+            closed[p] += closed[v];
+            estimate[p] += estimate[v];
+            estimate_closed[p] += estimate_closed[v];
+        }
+    }
+
+    let nodes = (0..n)
+        .map(|v| {
+            let descendants = open[v] + closed[v];
+            let progress = if descendants > 0 {
+                closed[v] as f64 / descendants as f64
+            } else if is_closed(v) {
+                1.0
+            } else {
+                0.0
+            };
+            let progress_estimate = if estimate[v] > 0.0 {
+                estimate_closed[v] / estimate[v]
+            } else {
+                progress
+            };
+            TreeNode {
+                node: v,
+                parent: parent[v],
+                depth: depth[v],
+                children: std::mem::take(&mut children[v]),
+                descendants_open: open[v],
+                descendants_closed: closed[v],
+                subtree_estimate: estimate[v],
+                subtree_estimate_closed: estimate_closed[v],
+                progress,
+                progress_estimate,
+            }
+        })
+        .collect();
+
+    HierarchyResult {
+        nodes,
+        roots,
+        order,
+    }
+}
+
+/// Drop invalid parent links (out of range, self-links, and loops).
+fn sanitize_parents(n: usize, parents: &[Option<usize>]) -> Vec<Option<usize>> {
+    let mut parent: Vec<Option<usize>> = (0..n)
+        .map(|v| {
+            parents
+                .get(v)
+                .copied()
+                .flatten()
+                .filter(|&p| p < n && p != v)
+        })
+        .collect();
+
+    // Break loops: walk up from each node; if we revisit a node on the
+    // current walk, cut the link that closed the loop.
+    // 0 = unvisited, 1 = on current walk, 2 = done
+    let mut state = vec![0u8; n];
+    for start in 0..n {
+        let mut path = Vec::new();
+        let mut v = start;
+        loop {
+            if state[v] == 2 {
+                break;
+            }
+            if state[v] == 1 {
+                // The last node on the path points back into the walk
+                if let Some(&last) = path.last() {
+                    parent[last] = None;
+                }
+                break;
+            }
+            state[v] = 1;
+            path.push(v);
+            match parent[v] {
+                Some(p) => v = p,
+                None => break,
+            }
+        }
+        for u in path {
+            state[u] = 2;
+        }
+    }
+
+    parent
+}
+
+/// One issue to create when cloning a subtree.
+#[derive(Debug, Clone, Serialize)]
+pub struct CloneItem {
+    /// Node being copied
+    pub source: usize,
+    /// Suggested title for the copy (after renaming)
+    pub title: String,
+    /// Parent of the copy as an index into the plan's items (None for the root)
+    pub parent_item: Option<usize>,
+    /// Existing parent of the root copy (the original root's parent)
+    pub parent_node: Option<usize>,
+    /// Blockers among the copies, as indices into the plan's items
+    pub blocked_by_items: Vec<usize>,
+    /// Blockers outside the subtree, shared with the original
+    pub blocked_by_nodes: Vec<usize>,
+}
+
+/// Ordered plan for cloning a subtree.
+#[derive(Debug, Clone, Serialize)]
+pub struct ClonePlan {
+    /// Items in creation order (pre-order: parents before children)
+    pub items: Vec<CloneItem>,
+}
+
+/// Plan a structural clone of `root` and its descendants.
+///
+/// Dependencies between subtree members are re-created between the
+/// copies; blockers outside the subtree block the copies too. Dependents
+/// outside the subtree are left alone, since they already wait on the
+/// original. Titles get every occurrence of `find` replaced by `replace`;
+/// an empty `find` keeps them unchanged. The caller creates the issues
+/// in order, assigning real IDs as it goes.
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `root` - Issue or epic to clone
+/// * `parents` - Parent index per node (None for top-level nodes)
+/// * `titles` - Per-node titles (missing entries are empty)
+/// * `find` - Text to rename in titles
+/// * `replace` - Replacement text
+pub fn clone_plan(
+    graph: &DiGraph,
+    root: usize,
+    parents: &[Option<usize>],
+    titles: &[String],
+    find: &str,
+    replace: &str,
+) -> ClonePlan {
+    let n = graph.len();
+    if root >= n {
+        return ClonePlan { items: Vec::new() };
+    }
+    let parent = sanitize_parents(n, parents);
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for (v, &p) in parent.iter().enumerate() {
+        if let Some(p) = p {
+            children[p].push(v);
+        }
+    }
+
+    let mut order = Vec::new();
+    let mut stack = vec![root];
+    while let Some(v) = stack.pop() {
+        order.push(v);
+        stack.extend(children[v].iter().rev());
+    }
+    let mut item_of: Vec<Option<usize>> = vec![None; n];
+    for (i, &v) in order.iter().enumerate() {
+        item_of[v] = Some(i);
+    }
+
+    let items = order
+        .iter()
+        .map(|&v| {
+            let title = titles.get(v).map(String::as_str).unwrap_or_default();
+            let (blocked_by_items, blocked_by_nodes): (Vec<usize>, Vec<usize>) = graph
+                .predecessors_slice(v)
+                .iter()
+                .partition(|&&u| item_of[u].is_some());
+            CloneItem {
+                source: v,
+                title: if find.is_empty() {
+                    title.to_string()
+                } else {
+                    title.replace(find, replace)
+                },
+                parent_item: if v == root {
+                    None
+                } else {
+                    parent[v].and_then(|p| item_of[p])
+                },
+                parent_node: if v == root { parent[v] } else { None },
+                blocked_by_items: blocked_by_items
+                    .iter()
+                    .filter_map(|&u| item_of[u])
+                    .collect(),
+                blocked_by_nodes,
+            }
+        })
+        .collect();
+    ClonePlan { items }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph_with(n: usize) -> DiGraph {
+        let mut graph = DiGraph::new();
+        for i in 0..n {
+            graph.add_node(&format!("n{}", i));
+        }
+        graph
+    }
+
+    #[test]
+    fn test_hierarchy_empty() {
+        let graph = DiGraph::new();
+        let result = hierarchy_rollup(&graph, &[], &[], &[]);
+        assert!(result.nodes.is_empty());
+        assert!(result.roots.is_empty());
+    }
+
+    #[test]
+    fn test_hierarchy_flat() {
+        let graph = graph_with(3);
+        let result = hierarchy_rollup(&graph, &[], &[], &[]);
+        assert_eq!(result.roots, vec![0, 1, 2]);
+        assert_eq!(result.order, vec![0, 1, 2]);
+        assert!(result.nodes.iter().all(|t| t.depth == 0));
+    }
+
+    #[test]
+    fn test_hierarchy_rollup_counts() {
+        // epic(0) -> story(1) -> task(2), task(3)
+        //         -> story(4)
+        let graph = graph_with(5);
+        let parents = vec![None, Some(0), Some(1), Some(1), Some(0)];
+        let closed = vec![false, false, true, false, true];
+        let estimates = vec![0.0, 1.0, 2.0, 3.0, 5.0];
+
+        let result = hierarchy_rollup(&graph, &parents, &closed, &estimates);
+        let epic = &result.nodes[0];
+        assert_eq!(epic.children, vec![1, 4]);
+        assert_eq!(epic.descendants_open, 2); // 1, 3
+        assert_eq!(epic.descendants_closed, 2); // 2, 4
+        assert_eq!(epic.subtree_estimate, 11.0);
+
+        let story = &result.nodes[1];
+        assert_eq!(story.depth, 1);
+        assert_eq!(story.descendants_open, 1);
+        assert_eq!(story.descendants_closed, 1);
+        assert_eq!(story.subtree_estimate, 6.0);
+
+        assert_eq!(result.order, vec![0, 1, 2, 3, 4]);
+        assert_eq!(result.nodes[2].depth, 2);
+    }
+
+    #[test]
+    fn test_hierarchy_progress() {
+        // epic(0) -> a(1, closed, 3), b(2, open, 1); c(3) is a closed leaf
+        let graph = graph_with(4);
+        let parents = vec![None, Some(0), Some(0), None];
+        let closed = vec![false, true, false, true];
+        let estimates = vec![0.0, 3.0, 1.0, 0.0];
+
+        let result = hierarchy_rollup(&graph, &parents, &closed, &estimates);
+        let epic = &result.nodes[0];
+        assert_eq!(epic.progress, 0.5);
+        assert_eq!(epic.subtree_estimate_closed, 3.0);
+        assert_eq!(epic.progress_estimate, 0.75);
+
+        // Leaves report their own status; no estimates falls back to counts
+        assert_eq!(result.nodes[2].progress, 0.0);
+        assert_eq!(result.nodes[3].progress, 1.0);
+        assert_eq!(result.nodes[3].progress_estimate, 1.0);
+    }
+
+    #[test]
+    fn test_hierarchy_parent_loop_is_broken() {
+        // 0 -> 1 -> 0 loop; both must still appear exactly once
+        let graph = graph_with(2);
+        let parents = vec![Some(1), Some(0)];
+        let result = hierarchy_rollup(&graph, &parents, &[], &[]);
+        assert_eq!(result.roots.len(), 1);
+        assert_eq!(result.order.len(), 2);
+    }
+
+    #[test]
+    fn test_hierarchy_invalid_parents_ignored() {
+        let graph = graph_with(2);
+        let parents = vec![Some(0), Some(99)];
+        let result = hierarchy_rollup(&graph, &parents, &[], &[]);
+        assert_eq!(result.roots, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_parents_from_i32() {
+        assert_eq!(parents_from_i32(&[-1, 0, 2]), vec![None, Some(0), Some(2)]);
+    }
+
+    #[test]
+    fn test_clone_plan_subtree() {
+        // epic(0) > a(1), b(2); x(3) blocks a, a blocks b, b blocks y(4)
+        let mut graph = DiGraph::new();
+        for id in ["epic", "a", "b", "x", "y"] {
+            graph.add_node(id);
+        }
+        graph.add_edge(3, 1);
+        graph.add_edge(1, 2);
+        graph.add_edge(2, 4);
+        let parents = vec![None, Some(0), Some(0), None, None];
+        let titles: Vec<String> = ["Q1 launch", "Q1 docs", "Q1 ship", "infra", "review"]
+            .iter()
+            .map(|t| t.to_string())
+            .collect();
+
+        let plan = clone_plan(&graph, 0, &parents, &titles, "Q1", "Q2");
+        let sources: Vec<usize> = plan.items.iter().map(|i| i.source).collect();
+        assert_eq!(sources, vec![0, 1, 2]);
+        assert_eq!(plan.items[0].title, "Q2 launch");
+        assert_eq!(plan.items[0].parent_node, None);
+        assert_eq!(plan.items[2].parent_item, Some(0));
+        // a's copy keeps the outside blocker; b's copy waits on a's copy
+        assert_eq!(plan.items[1].blocked_by_nodes, vec![3]);
+        assert_eq!(plan.items[2].blocked_by_items, vec![1]);
+        assert!(plan.items[2].blocked_by_nodes.is_empty());
+    }
+
+    #[test]
+    fn test_clone_plan_single_issue() {
+        let mut graph = DiGraph::new();
+        graph.add_node("epic");
+        graph.add_node("a");
+        let parents = vec![None, Some(0)];
+        let plan = clone_plan(&graph, 1, &parents, &[], "", "");
+        assert_eq!(plan.items.len(), 1);
+        assert_eq!(plan.items[0].parent_node, Some(0));
+        assert_eq!(plan.items[0].title, "");
+        assert!(clone_plan(&graph, 9, &parents, &[], "", "")
+            .items
+            .is_empty());
+    }
+}
@@ -0,0 +1,174 @@
+//! Label coupling analytics.
+//!
+//! Two views of how workstreams (labels) relate:
+//! - co-occurrence: how many issues carry both labels,
+//! - cross-label dependencies: how many blocking edges run from an issue
+//!   with one label to an issue with the other.
+//!
+//! A pair with few shared issues but many cross edges points at hidden
+//! coupling between teams.
+
+use crate::graph::DiGraph;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Coupling counts for an unordered label pair.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct LabelPair {
+    /// Lexicographically smaller label
+    pub a: String,
+    /// Lexicographically larger label
+    pub b: String,
+    /// Issues carrying both labels
+    pub co_occurrence: usize,
+    /// Edges between an issue labeled `a` and one labeled `b` (either direction)
+    pub cross_edges: usize,
+}
+
+/// Result of label coupling analysis.
+#[derive(Debug, Clone, Serialize)]
+pub struct LabelCoupling {
+    /// All labels seen, sorted (heatmap axis)
+    pub labels: Vec<String>,
+    /// Issue count per label, parallel to `labels`
+    pub counts: Vec<usize>,
+    /// Pairs with any coupling, sorted by cross_edges desc, co_occurrence desc, then name
+    pub pairs: Vec<LabelPair>,
+}
+
+/// Compute label co-occurrence and cross-label dependency counts.
+///
+/// Each edge counts at most once per label pair it connects. An edge between
+/// two issues with the same label does not couple that label with itself.
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `labels` - Labels per node, parallel to node indices (missing = unlabeled)
+pub fn label_coupling(graph: &DiGraph, labels: &[Vec<String>]) -> LabelCoupling {
+    let n = graph.len();
+    let node_labels: Vec<Vec<&str>> = (0..n)
+        .map(|v| {
+            let mut ls: Vec<&str> = labels
+                .get(v)
+                .map(|ls| {
+                    ls.iter()
+                        .map(String::as_str)
+                        .filter(|l| !l.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default();
+            ls.sort_unstable();
+            ls.dedup();
+            ls
+        })
+        .collect();
+
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut pairs: BTreeMap<(&str, &str), (usize, usize)> = BTreeMap::new();
+
+    for ls in &node_labels {
+        for (i, &a) in ls.iter().enumerate() {
+            *counts.entry(a).or_insert(0) += 1;
+            for &b in &ls[i + 1..] {
+                pairs.entry((a, b)).or_insert((0, 0)).0 += 1;
+            }
+        }
+    }
+
+    for (u, v) in graph.edges() {
+        let mut seen: Vec<(&str, &str)> = Vec::new();
+        for &a in &node_labels[u] {
+            for &b in &node_labels[v] {
+                if a == b {
+                    continue;
+                }
+                let key = if a < b { (a, b) } else { (b, a) };
+                if !seen.contains(&key) {
+                    seen.push(key);
+                    pairs.entry(key).or_insert((0, 0)).1 += 1;
+                }
+            }
+        }
+    }
+
+    let mut pairs: Vec<LabelPair> = pairs
+        .into_iter()
+        .map(|((a, b), (co, cross))| LabelPair {
+            a: a.to_string(),
+            b: b.to_string(),
+            co_occurrence: co,
+            cross_edges: cross,
+        })
+        .collect();
+    pairs.sort_by(|x, y| {
+        y.cross_edges
+            .cmp(&x.cross_edges)
+            .then(y.co_occurrence.cmp(&x.co_occurrence))
+            .then_with(|| (&x.a, &x.b).cmp(&(&y.a, &y.b)))
+    });
+
+    LabelCoupling {
+        labels: counts.keys().map(|l| l.to_string()).collect(),
+        counts: counts.values().copied().collect(),
+        pairs,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(ls: &[&[&str]]) -> Vec<Vec<String>> {
+        ls.iter()
+            .map(|l| l.iter().map(|s| s.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_label_coupling_empty() {
+        let graph = DiGraph::new();
+        let result = label_coupling(&graph, &[]);
+        assert!(result.labels.is_empty());
+        assert!(result.pairs.is_empty());
+    }
+
+    #[test]
+    fn test_label_co_occurrence() {
+        let mut graph = DiGraph::new();
+        graph.add_node("a");
+        graph.add_node("b");
+        graph.add_node("c");
+        let ls = labels(&[&["ui", "auth"], &["auth", "ui", "ui"], &["db"]]);
+
+        let result = label_coupling(&graph, &ls);
+        assert_eq!(result.labels, vec!["auth", "db", "ui"]);
+        assert_eq!(result.counts, vec![2, 1, 2]);
+        assert_eq!(result.pairs.len(), 1);
+        assert_eq!(result.pairs[0].co_occurrence, 2);
+        assert_eq!(result.pairs[0].cross_edges, 0);
+    }
+
+    #[test]
+    fn test_label_cross_edges() {
+        // db issue blocks two ui issues; a ui -> ui edge is not cross-label
+        let mut graph = DiGraph::new();
+        let d = graph.add_node("d");
+        let u1 = graph.add_node("u1");
+        let u2 = graph.add_node("u2");
+        graph.add_edge(d, u1);
+        graph.add_edge(d, u2);
+        graph.add_edge(u1, u2);
+        let ls = labels(&[&["db"], &["ui"], &["ui"]]);
+
+        let result = label_coupling(&graph, &ls);
+        assert_eq!(
+            result.pairs,
+            vec![LabelPair {
+                a: "db".to_string(),
+                b: "ui".to_string(),
+                co_occurrence: 0,
+                cross_edges: 2,
+            }]
+        );
+    }
+}
@@ -0,0 +1,234 @@
+//! Layered graph layout for the graph view and its minimap.
+//!
+//! A simplified Sugiyama layout:
+//! 1. Layers: longest path from the roots, computed on the condensation so
+//!    every node of a dependency cycle shares one layer.
+//! 2. Order within layers: barycenter sweeps (down, then up) to reduce edge
+//!    crossings, seeded with node index order so output is deterministic.
+//! 3. Coordinates: layer is `y`, position is `x`, with each layer centered
+//!    on the widest one.
+//!
+//! The view scales these unit coordinates to cells; the minimap draws the
+//! same layout shrunk to fit, with the viewport rectangle on top.
+
+use crate::algorithms::cycles::tarjan_scc;
+use crate::graph::DiGraph;
+use serde::Serialize;
+use std::collections::VecDeque;
+
+/// Number of down+up barycenter sweeps.
+const SWEEPS: usize = 4;
+
+/// Placement of one node.
+#[derive(Debug, Clone, Serialize)]
+pub struct LayoutNode {
+    /// Node index in the graph
+    pub node: usize,
+    /// Layer (0 = roots)
+    pub layer: usize,
+    /// Position within the layer, left to right
+    pub position: usize,
+    /// Horizontal coordinate (position, shifted to center the layer)
+    pub x: f64,
+    /// Vertical coordinate (the layer)
+    pub y: f64,
+}
+
+/// Result of the layered layout.
+#[derive(Debug, Clone, Serialize)]
+pub struct Layout {
+    /// Per-node placements in node index order
+    pub nodes: Vec<LayoutNode>,
+    /// Node indices per layer, in left-to-right order
+    pub layers: Vec<Vec<usize>>,
+    /// Size of the widest layer (x spans 0..width)
+    pub width: usize,
+}
+
+/// Assign each node the longest-path layer of its strongly connected component.
+fn assign_layers(graph: &DiGraph) -> Vec<usize> {
+    let n = graph.len();
+    let components = tarjan_scc(graph).components;
+    let mut comp = vec![0usize; n];
+    for (c, members) in components.iter().enumerate() {
+        for &v in members {
+            comp[v] = c;
+        }
+    }
+
+    // Kahn's algorithm on the condensation
+    let k = components.len();
+    let mut indegree = vec![0usize; k];
+    for (u, v) in graph.edges() {
+        if comp[u] != comp[v] {
+            indegree[comp[v]] += 1;
+        }
+    }
+    let mut layer = vec![0usize; k];
+    let mut queue: VecDeque<usize> = (0..k).filter(|&c| indegree[c] == 0).collect();
+    while let Some(c) = queue.pop_front() {
+        for &u in &components[c] {
+            for &v in graph.successors_slice(u) {
+                let d = comp[v];
+                if d == c {
+                    continue;
+                }
+                layer[d] = layer[d].max(layer[c] + 1);
+                indegree[d] -= 1;
+                if indegree[d] == 0 {
+                    queue.push_back(d);
+                }
+            }
+        }
+    }
+    (0..n).map(|v| layer[comp[v]]).collect()
+}
+
+/// Reorder one layer by the mean position of its neighbors in the adjacent layer.
+fn reorder<'g>(
+    nodes: &mut [usize],
+    position: &[usize],
+    layer_of: &[usize],
+    adjacent: usize,
+    neighbors: impl Fn(usize) -> &'g [usize],
+) {
+    let mut keys: Vec<(usize, f64)> = nodes
+        .iter()
+        .map(|&v| {
+            let positions: Vec<f64> = neighbors(v)
+                .iter()
+                .filter(|&&w| layer_of[w] == adjacent)
+                .map(|&w| position[w] as f64)
+                .collect();
+            let key = if positions.is_empty() {
+                position[v] as f64
+            } else {
+                positions.iter().sum::<f64>() / positions.len() as f64
+            };
+            (v, key)
+        })
+        .collect();
+    keys.sort_by(|(a, ka), (b, kb)| {
+        ka.partial_cmp(kb)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(position[*a].cmp(&position[*b]))
+    });
+    for (slot, (v, _)) in nodes.iter_mut().zip(keys) {
+        *slot = v;
+    }
+}
+
+/// Compute the layered layout.
+pub fn layered_layout(graph: &DiGraph) -> Layout {
+    let n = graph.len();
+    let layer_of = assign_layers(graph);
+    let depth = layer_of.iter().map(|&l| l + 1).max().unwrap_or(0);
+    let mut layers: Vec<Vec<usize>> = vec![Vec::new(); depth];
+    for v in 0..n {
+        layers[layer_of[v]].push(v);
+    }
+
+    let mut position = vec![0usize; n];
+    let sync = |layer: &[usize], position: &mut [usize]| {
+        for (i, &v) in layer.iter().enumerate() {
+            position[v] = i;
+        }
+    };
+    for layer in &layers {
+        sync(layer, &mut position);
+    }
+    for _ in 0..SWEEPS {
+        for (l, layer) in layers.iter_mut().enumerate().skip(1) {
+            let preds = |v: usize| graph.predecessors_slice(v);
+            reorder(layer, &position, &layer_of, l - 1, preds);
+            sync(layer, &mut position);
+        }
+        for (l, layer) in layers.iter_mut().enumerate().rev().skip(1) {
+            let succs = |v: usize| graph.successors_slice(v);
+            reorder(layer, &position, &layer_of, l + 1, succs);
+            sync(layer, &mut position);
+        }
+    }
+
+    let width = layers.iter().map(Vec::len).max().unwrap_or(0);
+    let nodes = (0..n)
+        .map(|v| {
+            let offset = (width - layers[layer_of[v]].len()) as f64 / 2.0;
+            LayoutNode {
+                node: v,
+                layer: layer_of[v],
+                position: position[v],
+                x: position[v] as f64 + offset,
+                y: layer_of[v] as f64,
+            }
+        })
+        .collect();
+    Layout {
+        nodes,
+        layers,
+        width,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_empty() {
+        let layout = layered_layout(&DiGraph::new());
+        assert!(layout.nodes.is_empty());
+        assert_eq!(layout.width, 0);
+    }
+
+    #[test]
+    fn test_layout_layers_and_centering() {
+        // a -> b -> c, a -> c, d isolated
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(a, c);
+
+        let layout = layered_layout(&graph);
+        let layers: Vec<usize> = layout.nodes.iter().map(|n| n.layer).collect();
+        assert_eq!(layers, vec![0, 1, 2, 0]);
+        assert_eq!(layout.width, 2);
+        // b is alone in its layer, so it is centered
+        assert_eq!(layout.nodes[b].x, 0.5);
+    }
+
+    #[test]
+    fn test_layout_reduces_crossings() {
+        // a -> d, b -> c: index order would cross the edges
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, d);
+        graph.add_edge(b, c);
+
+        let layout = layered_layout(&graph);
+        assert_eq!(layout.layers[1], vec![d, c]);
+    }
+
+    #[test]
+    fn test_layout_cycle_shares_layer() {
+        // a <-> b, b -> c
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+        graph.add_edge(b, c);
+
+        let layout = layered_layout(&graph);
+        assert_eq!(layout.nodes[a].layer, layout.nodes[b].layer);
+        assert_eq!(layout.nodes[c].layer, 1);
+    }
+}
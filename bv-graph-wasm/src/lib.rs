@@ -11,6 +11,21 @@ mod advanced;
 mod whatif;
 mod subgraph;
 mod reachability;
+mod neighborhood;
+mod layout;
+mod collapse;
+mod hierarchy;
+mod fuzzy;
+mod filter;
+mod records;
+mod grouping;
+mod remap;
+mod diff;
+mod health;
+mod labels;
+mod expr;
+mod forecast;
+mod export;
 
 pub use graph::DiGraph;
 
@@ -0,0 +1,168 @@
+//! On-demand neighborhood views for the graph view.
+//!
+//! Instead of laying out the whole graph, the view starts from a focus
+//! issue and the user expands individual nodes' blockers (up) or
+//! dependents (down). The visible set is recomputed from the focus and the
+//! two expansion sets, so collapsing a node also drops whatever was only
+//! visible through it.
+
+use crate::graph::DiGraph;
+use serde::Serialize;
+use std::collections::VecDeque;
+
+/// A visible node with the expander state the view draws next to it.
+#[derive(Debug, Clone, Serialize)]
+pub struct NeighborhoodNode {
+    /// Node index in the graph
+    pub node: usize,
+    /// Node ID for display
+    pub id: Option<String>,
+    /// Blockers not currently visible (a "+N" expander above the node)
+    pub hidden_blockers: usize,
+    /// Dependents not currently visible (a "+N" expander below the node)
+    pub hidden_dependents: usize,
+    /// Whether the node's blockers are expanded
+    pub expanded_up: bool,
+    /// Whether the node's dependents are expanded
+    pub expanded_down: bool,
+}
+
+/// The visible part of the graph.
+#[derive(Debug, Clone, Serialize)]
+pub struct Neighborhood {
+    /// Visible nodes in discovery order, focus first
+    pub nodes: Vec<NeighborhoodNode>,
+    /// Edges between visible nodes as (blocker, blocked) graph indices
+    pub edges: Vec<(usize, usize)>,
+}
+
+/// Compute the visible neighborhood of `focus`.
+///
+/// A node's blockers are shown when it is in `expand_up`, its dependents
+/// when it is in `expand_down`. Expansion entries for nodes that are not
+/// visible have no effect. An out-of-range focus yields an empty view.
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `focus` - Node the view starts from
+/// * `expand_up` - Nodes whose blockers are expanded
+/// * `expand_down` - Nodes whose dependents are expanded
+pub fn neighborhood(
+    graph: &DiGraph,
+    focus: usize,
+    expand_up: &[usize],
+    expand_down: &[usize],
+) -> Neighborhood {
+    let n = graph.len();
+    if focus >= n {
+        return Neighborhood {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        };
+    }
+    let flags = |nodes: &[usize]| {
+        let mut set = vec![false; n];
+        for &v in nodes.iter().filter(|&&v| v < n) {
+            set[v] = true;
+        }
+        set
+    };
+    let up = flags(expand_up);
+    let down = flags(expand_down);
+
+    let mut visible = vec![false; n];
+    let mut order = vec![focus];
+    let mut queue = VecDeque::from([focus]);
+    visible[focus] = true;
+    while let Some(v) = queue.pop_front() {
+        let mut reveal: Vec<usize> = Vec::new();
+        if up[v] {
+            reveal.extend(graph.predecessors_slice(v));
+        }
+        if down[v] {
+            reveal.extend(graph.successors_slice(v));
+        }
+        for w in reveal {
+            if !visible[w] {
+                visible[w] = true;
+                order.push(w);
+                queue.push_back(w);
+            }
+        }
+    }
+
+    let hidden = |nodes: &[usize]| nodes.iter().filter(|&&w| !visible[w]).count();
+    let nodes = order
+        .iter()
+        .map(|&v| NeighborhoodNode {
+            node: v,
+            id: graph.node_id(v),
+            hidden_blockers: hidden(graph.predecessors_slice(v)),
+            hidden_dependents: hidden(graph.successors_slice(v)),
+            expanded_up: up[v],
+            expanded_down: down[v],
+        })
+        .collect();
+    let edges = order
+        .iter()
+        .flat_map(|&u| {
+            graph
+                .successors_slice(u)
+                .iter()
+                .filter(|&&w| visible[w])
+                .map(move |&w| (u, w))
+        })
+        .collect();
+    Neighborhood { nodes, edges }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -> b -> c -> d, e -> c
+    fn chain() -> DiGraph {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        let e = graph.add_node("e");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, d);
+        graph.add_edge(e, c);
+        graph
+    }
+
+    fn visible(view: &Neighborhood) -> Vec<usize> {
+        view.nodes.iter().map(|n| n.node).collect()
+    }
+
+    #[test]
+    fn test_neighborhood_focus_only() {
+        let graph = chain();
+        let view = neighborhood(&graph, 2, &[], &[]);
+        assert_eq!(visible(&view), vec![2]);
+        assert_eq!(view.nodes[0].hidden_blockers, 2);
+        assert_eq!(view.nodes[0].hidden_dependents, 1);
+        assert!(view.edges.is_empty());
+        assert!(neighborhood(&graph, 99, &[], &[]).nodes.is_empty());
+    }
+
+    #[test]
+    fn test_neighborhood_expand_and_collapse() {
+        let graph = chain();
+        // Expand c up, then b up: a, b, e appear
+        let view = neighborhood(&graph, 2, &[2, 1], &[]);
+        assert_eq!(visible(&view), vec![2, 1, 4, 0]);
+        assert_eq!(view.edges, vec![(1, 2), (4, 2), (0, 1)]);
+        assert_eq!(view.nodes[0].hidden_blockers, 0);
+        assert!(view.nodes[0].expanded_up);
+
+        // Collapsing c hides b, and with it a, even though b stays "expanded"
+        let view = neighborhood(&graph, 2, &[1], &[2]);
+        assert_eq!(visible(&view), vec![2, 3]);
+        assert_eq!(view.edges, vec![(2, 3)]);
+    }
+}
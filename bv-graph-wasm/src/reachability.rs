@@ -4,7 +4,8 @@
 //! Essential for impact analysis and dependency exploration.
 
 use crate::graph::DiGraph;
-use std::collections::VecDeque;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashSet, VecDeque};
 
 /// Find all nodes reachable from source (BFS forward).
 /// Returns all nodes in the forward closure, including the source.
@@ -111,6 +112,313 @@ pub fn open_blocker_count(graph: &DiGraph, node: usize, closed_set: &[bool]) ->
         .count()
 }
 
+/// Transitive open blockers of a node, grouped by distance.
+#[derive(Debug, Clone, Serialize)]
+pub struct BlockingChain {
+    /// levels[0] holds direct open blockers, levels[1] their open blockers, and so on.
+    /// Each blocker appears once, at the level where it is first reached.
+    pub levels: Vec<Vec<usize>>,
+    /// Blockers in the chain that have no open blockers themselves (root causes)
+    pub roots: Vec<usize>,
+}
+
+/// Walk the open blocker chain of a node level by level (BFS backward).
+/// Closed blockers are skipped, so the chain only shows what is still in the way.
+/// Nodes within a level are in index order.
+pub fn blocking_chain(graph: &DiGraph, node: usize, closed_set: &[bool]) -> BlockingChain {
+    let n = graph.len();
+    let mut levels = Vec::new();
+    let mut roots = Vec::new();
+    if node >= n {
+        return BlockingChain { levels, roots };
+    }
+
+    let mut visited = vec![false; n];
+    visited[node] = true;
+    let mut frontier = vec![node];
+
+    while !frontier.is_empty() {
+        let mut next = Vec::new();
+        for &v in &frontier {
+            for p in open_blockers(graph, v, closed_set) {
+                if !visited[p] {
+                    visited[p] = true;
+                    next.push(p);
+                }
+            }
+        }
+        next.sort_unstable();
+        for &p in &next {
+            if open_blocker_count(graph, p, closed_set) == 0 {
+                roots.push(p);
+            }
+        }
+        if !next.is_empty() {
+            levels.push(next.clone());
+        }
+        frontier = next;
+    }
+
+    roots.sort_unstable();
+    BlockingChain { levels, roots }
+}
+
+/// Shortest dependency path from source to target (BFS forward).
+/// Returns the node sequence including both endpoints, or None if unreachable.
+pub fn shortest_path(graph: &DiGraph, source: usize, target: usize) -> Option<Vec<usize>> {
+    let n = graph.len();
+    if source >= n || target >= n {
+        return None;
+    }
+
+    let mut parent: Vec<Option<usize>> = vec![None; n];
+    let mut visited = vec![false; n];
+    let mut queue = VecDeque::new();
+
+    queue.push_back(source);
+    visited[source] = true;
+
+    while let Some(v) = queue.pop_front() {
+        if v == target {
+            let mut path = vec![target];
+            let mut cur = target;
+            while let Some(p) = parent[cur] {
+                path.push(p);
+                cur = p;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for &w in graph.successors_slice(v) {
+            if !visited[w] {
+                visited[w] = true;
+                parent[w] = Some(v);
+                queue.push_back(w);
+            }
+        }
+    }
+
+    None
+}
+
+/// Dependency paths between two issues, for path highlighting.
+#[derive(Debug, Clone, Serialize)]
+pub struct PathSet {
+    /// Simple paths from source to target, shortest first (longest first when
+    /// asked), ties by node order
+    pub paths: Vec<Vec<usize>>,
+    /// Whether more paths exist than were enumerated
+    pub truncated: bool,
+    /// Every node on some source-to-target walk, in index order
+    pub nodes: Vec<usize>,
+    /// Every edge between those nodes that lies on such a walk
+    pub edges: Vec<(usize, usize)>,
+}
+
+/// Topological order of the nodes in `region`, or None if it has a cycle.
+fn region_order(graph: &DiGraph, region: &[bool]) -> Option<Vec<usize>> {
+    let n = graph.len();
+    let mut indegree = vec![0usize; n];
+    for (u, v) in graph.edges() {
+        if region[u] && region[v] {
+            indegree[v] += 1;
+        }
+    }
+    let mut queue: VecDeque<usize> = (0..n).filter(|&v| region[v] && indegree[v] == 0).collect();
+    let mut order = Vec::new();
+    while let Some(v) = queue.pop_front() {
+        order.push(v);
+        for &w in graph.successors_slice(v) {
+            if region[w] {
+                indegree[w] -= 1;
+                if indegree[w] == 0 {
+                    queue.push_back(w);
+                }
+            }
+        }
+    }
+    (order.len() == region.iter().filter(|&&r| r).count()).then_some(order)
+}
+
+/// Best path from `from` to `to` through `allowed` nodes, skipping `banned`
+/// edges: the shortest, or the longest when `order` (a topological order
+/// of the allowed nodes) is given. Ties go to the smallest successor.
+fn best_path(
+    graph: &DiGraph,
+    allowed: &[bool],
+    banned: &HashSet<(usize, usize)>,
+    from: usize,
+    to: usize,
+    order: Option<&[usize]>,
+) -> Option<Vec<usize>> {
+    let usable = |u: usize, v: usize| allowed[u] && allowed[v] && !banned.contains(&(u, v));
+    // Edges left to `to` along the best path from each node
+    let mut rest: Vec<Option<usize>> = vec![None; graph.len()];
+    rest[to] = Some(0);
+    match order {
+        Some(order) => {
+            for &v in order.iter().rev().filter(|&&v| v != to && allowed[v]) {
+                rest[v] = graph
+                    .successors_slice(v)
+                    .iter()
+                    .filter(|&&w| usable(v, w))
+                    .filter_map(|&w| rest[w])
+                    .max()
+                    .map(|r| r + 1);
+            }
+        }
+        None => {
+            let mut queue = VecDeque::from([to]);
+            while let Some(v) = queue.pop_front() {
+                let steps = rest[v].map(|r| r + 1);
+                for &u in graph.predecessors_slice(v) {
+                    if rest[u].is_none() && usable(u, v) {
+                        rest[u] = steps;
+                        queue.push_back(u);
+                    }
+                }
+            }
+        }
+    }
+    if !allowed[from] {
+        return None;
+    }
+
+    let mut steps = rest[from]?;
+    let mut path = vec![from];
+    let mut cur = from;
+    while steps > 0 {
+        steps -= 1;
+        cur = graph
+            .successors_slice(cur)
+            .iter()
+            .copied()
+            .filter(|&w| usable(cur, w) && rest[w] == Some(steps))
+            .min()?;
+        path.push(cur);
+    }
+    Some(path)
+}
+
+/// Enumerate the `limit` shortest (or longest) simple paths from source to
+/// target.
+///
+/// Uses Yen's k-shortest-paths algorithm restricted to the nodes that lie
+/// between the two issues, so with `truncated` set the listed paths are
+/// still the best `limit` by length. Longest-first ranking needs that
+/// region to be acyclic (longest simple paths are intractable otherwise)
+/// and returns no paths if it is not, as `k_critical_paths` does for
+/// cyclic graphs. `nodes` and `edges` cover the whole region regardless of
+/// the limit or ordering.
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `source` - First issue on every path
+/// * `target` - Last issue on every path
+/// * `limit` - Maximum number of paths to return
+/// * `longest` - Rank longest paths first instead of shortest
+pub fn all_paths(
+    graph: &DiGraph,
+    source: usize,
+    target: usize,
+    limit: usize,
+    longest: bool,
+) -> PathSet {
+    let n = graph.len();
+    let mut result = PathSet {
+        paths: Vec::new(),
+        truncated: false,
+        nodes: Vec::new(),
+        edges: Vec::new(),
+    };
+    if source >= n || target >= n {
+        return result;
+    }
+
+    let mut forward = vec![false; n];
+    for v in reachable_from(graph, source) {
+        forward[v] = true;
+    }
+    let mut backward = vec![false; n];
+    for v in reachable_to(graph, target) {
+        backward[v] = true;
+    }
+    let region: Vec<bool> = (0..n).map(|v| forward[v] && backward[v]).collect();
+    result.nodes = (0..n).filter(|&v| region[v]).collect();
+    result.edges = graph
+        .edges()
+        .filter(|&(u, v)| region[u] && region[v])
+        .collect();
+    if !region[source] {
+        return result;
+    }
+
+    let order = if longest {
+        match region_order(graph, &region) {
+            Some(order) => Some(order),
+            None => return result,
+        }
+    } else {
+        None
+    };
+    let order = order.as_deref();
+    let key = |path: Vec<usize>| {
+        let len = path.len() as i64;
+        (if longest { -len } else { len }, path)
+    };
+
+    // Yen: each next path deviates from the last one found at some spur
+    // node, avoiding the edges earlier paths took from the same root
+    let Some(first) = best_path(graph, &region, &HashSet::new(), source, target, order) else {
+        return result;
+    };
+    let mut found = vec![first];
+    let mut candidates: BTreeSet<(i64, Vec<usize>)> = BTreeSet::new();
+    while found.len() <= limit {
+        let prev = found[found.len() - 1].clone();
+        for i in 0..prev.len() - 1 {
+            let root = &prev[..=i];
+            let banned: HashSet<(usize, usize)> = found
+                .iter()
+                .filter(|p| p.len() > i + 1 && p[..=i] == *root)
+                .map(|p| (p[i], p[i + 1]))
+                .collect();
+            let mut allowed = region.clone();
+            for &v in &root[..i] {
+                allowed[v] = false;
+            }
+            if let Some(spur) = best_path(graph, &allowed, &banned, prev[i], target, order) {
+                let mut path = root[..i].to_vec();
+                path.extend(spur);
+                if !found.contains(&path) {
+                    candidates.insert(key(path));
+                }
+            }
+        }
+        match candidates.pop_first() {
+            Some((_, path)) => found.push(path),
+            None => break,
+        }
+    }
+
+    result.truncated = found.len() > limit;
+    found.truncate(limit);
+    found.sort_by_cached_key(|p| key(p.clone()));
+    result.paths = found;
+    result
+}
+
+/// Check whether adding the edge from -> to would close a dependency cycle.
+/// Returns the would-be cycle as [from, to, ...]: the new edge followed by the
+/// existing path from `to` back to `from`. Returns None if the edge is safe.
+pub fn would_create_cycle(graph: &DiGraph, from: usize, to: usize) -> Option<Vec<usize>> {
+    let mut path = shortest_path(graph, to, from)?;
+    // path runs to -> ... -> from; rotate so the proposed edge comes first
+    path.pop();
+    path.insert(0, from);
+    Some(path)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -402,4 +710,162 @@ mod tests {
         let to_a = reachable_to(&graph, a);
         assert_eq!(to_a.len(), 3);
     }
+
+    #[test]
+    fn test_shortest_path() {
+        // a -> b -> c -> d, a -> d
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, d);
+        graph.add_edge(a, d);
+
+        assert_eq!(shortest_path(&graph, a, d), Some(vec![a, d]));
+        assert_eq!(shortest_path(&graph, b, d), Some(vec![b, c, d]));
+        assert_eq!(shortest_path(&graph, a, a), Some(vec![a]));
+        assert_eq!(shortest_path(&graph, d, a), None);
+        assert_eq!(shortest_path(&graph, a, 99), None);
+    }
+
+    #[test]
+    fn test_all_paths() {
+        // a -> b -> d, a -> c -> d, a -> d, d -> e, x -> d
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        let e = graph.add_node("e");
+        let x = graph.add_node("x");
+        graph.add_edge(a, b);
+        graph.add_edge(a, c);
+        graph.add_edge(b, d);
+        graph.add_edge(c, d);
+        graph.add_edge(a, d);
+        graph.add_edge(d, e);
+        graph.add_edge(x, d);
+
+        let result = all_paths(&graph, a, d, 10, false);
+        assert_eq!(result.paths, vec![vec![a, d], vec![a, b, d], vec![a, c, d]]);
+        assert!(!result.truncated);
+        assert_eq!(result.nodes, vec![a, b, c, d]);
+        assert_eq!(result.edges.len(), 5);
+
+        let limited = all_paths(&graph, a, d, 2, false);
+        assert_eq!(limited.paths.len(), 2);
+        assert!(limited.truncated);
+        assert_eq!(limited.nodes.len(), 4);
+
+        assert!(all_paths(&graph, e, a, 10, false).paths.is_empty());
+        assert_eq!(all_paths(&graph, a, a, 10, false).paths, vec![vec![a]]);
+    }
+
+    #[test]
+    fn test_all_paths_cycle() {
+        // a -> b -> c, b -> a
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(b, a);
+
+        let result = all_paths(&graph, a, c, 10, false);
+        assert_eq!(result.paths, vec![vec![a, b, c]]);
+        assert!(result.edges.contains(&(b, a)));
+
+        // Longest simple paths are not ranked through a cycle
+        let result = all_paths(&graph, a, c, 10, true);
+        assert!(result.paths.is_empty());
+        assert_eq!(result.nodes, vec![a, b, c]);
+    }
+
+    #[test]
+    fn test_all_paths_ranked_by_length() {
+        // a -> b -> c -> d -> e and a shortcut a -> e added last, so a
+        // depth-first search finds the long path first
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        let e = graph.add_node("e");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, d);
+        graph.add_edge(d, e);
+        graph.add_edge(b, d);
+        graph.add_edge(a, e);
+
+        let shortest = all_paths(&graph, a, e, 1, false);
+        assert_eq!(shortest.paths, vec![vec![a, e]]);
+        assert!(shortest.truncated);
+        let shortest = all_paths(&graph, a, e, 2, false);
+        assert_eq!(shortest.paths, vec![vec![a, e], vec![a, b, d, e]]);
+
+        let longest = all_paths(&graph, a, e, 2, true);
+        assert_eq!(longest.paths, vec![vec![a, b, c, d, e], vec![a, b, d, e]]);
+        assert!(longest.truncated);
+        let all = all_paths(&graph, a, e, 10, true);
+        assert_eq!(all.paths.len(), 3);
+        assert!(!all.truncated);
+        assert!(all_paths(&graph, a, e, 0, false).truncated);
+    }
+
+    #[test]
+    fn test_would_create_cycle() {
+        // a -> b -> c
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        assert_eq!(would_create_cycle(&graph, a, c), None);
+        assert_eq!(would_create_cycle(&graph, c, a), Some(vec![c, a, b]));
+        assert_eq!(would_create_cycle(&graph, b, b), Some(vec![b]));
+        assert_eq!(would_create_cycle(&graph, a, 99), None);
+    }
+
+    #[test]
+    fn test_blocking_chain_levels() {
+        // r1 -> m -> target, r2 -> target, done -> m (closed)
+        let mut graph = DiGraph::new();
+        let r1 = graph.add_node("r1");
+        let m = graph.add_node("m");
+        let target = graph.add_node("target");
+        let r2 = graph.add_node("r2");
+        let done = graph.add_node("done");
+        graph.add_edge(r1, m);
+        graph.add_edge(m, target);
+        graph.add_edge(r2, target);
+        graph.add_edge(done, m);
+
+        let closed = vec![false, false, false, false, true];
+        let chain = blocking_chain(&graph, target, &closed);
+        assert_eq!(chain.levels, vec![vec![m, r2], vec![r1]]);
+        assert_eq!(chain.roots, vec![r1, r2]);
+    }
+
+    #[test]
+    fn test_blocking_chain_cycle_and_invalid() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+
+        let chain = blocking_chain(&graph, a, &[]);
+        assert_eq!(chain.levels, vec![vec![b]]);
+        // b is blocked by a, so the cycle has no root cause
+        assert!(chain.roots.is_empty());
+
+        assert!(blocking_chain(&graph, 99, &[]).levels.is_empty());
+    }
 }
@@ -0,0 +1,266 @@
+//! Tolerant loading of issue records.
+//!
+//! Issue files evolve: fields get renamed, older exports use other names,
+//! and hand-edited records carry the odd wrong type. Instead of rejecting
+//! the whole batch when one record does not match [`IssueRecord`], records
+//! are read field by field:
+//! - unknown fields are ignored,
+//! - missing fields take their defaults,
+//! - field names from older formats are mapped to current ones,
+//! - values of the wrong type are coerced where unambiguous and dropped
+//!   otherwise.
+//!
+//! Every repair is reported as a [`RecordWarning`] so a diagnostics pane
+//! can show what was read differently from the file.
+
+use crate::filter::IssueRecord;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Field names used by older formats, mapped to current names.
+const FIELD_ALIASES: &[(&str, &str)] = &[
+    ("type", "issue_type"),
+    ("owner", "assignee"),
+    ("tags", "labels"),
+    ("created", "created_at"),
+    ("updated", "updated_at"),
+    ("closed", "closed_at"),
+    ("points", "estimate"),
+];
+
+/// A repair made while loading one record.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct RecordWarning {
+    /// Index of the record (parallel to node indices)
+    pub index: usize,
+    /// Field the warning is about (as written in the input; empty for the whole record)
+    pub field: String,
+    /// Human-readable description of the repair
+    pub message: String,
+}
+
+/// Records plus the warnings collected while loading them.
+#[derive(Debug, Clone, Default)]
+pub struct LoadedRecords {
+    pub records: Vec<IssueRecord>,
+    pub warnings: Vec<RecordWarning>,
+}
+
+/// Read a string field; numbers and booleans are stringified.
+fn string_value(value: &Value) -> Result<String, &'static str> {
+    match value {
+        Value::Null => Ok(String::new()),
+        Value::String(s) => Ok(s.clone()),
+        Value::Number(n) => Ok(n.to_string()),
+        Value::Bool(b) => Ok(b.to_string()),
+        _ => Err("expected a string"),
+    }
+}
+
+/// Read a priority: an integer, an integral float, or a string like `2` or `P2`.
+fn priority_value(value: &Value) -> Result<Option<i64>, &'static str> {
+    match value {
+        Value::Null => Ok(None),
+        Value::Number(n) => n
+            .as_i64()
+            .or_else(|| n.as_f64().filter(|f| f.fract() == 0.0).map(|f| f as i64))
+            .map(Some)
+            .ok_or("expected an integer priority"),
+        Value::String(s) => {
+            let s = s.trim();
+            let digits = s.strip_prefix(['P', 'p']).unwrap_or(s);
+            digits
+                .parse()
+                .map(Some)
+                .map_err(|_| "expected an integer priority")
+        }
+        _ => Err("expected an integer priority"),
+    }
+}
+
+/// Read an estimate: a non-negative number, or a string holding one.
+fn estimate_value(value: &Value) -> Result<Option<f64>, &'static str> {
+    let estimate = match value {
+        Value::Null => return Ok(None),
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse::<f64>().ok(),
+        _ => None,
+    };
+    estimate
+        .filter(|e| e.is_finite() && *e >= 0.0)
+        .map(Some)
+        .ok_or("expected a non-negative estimate")
+}
+
+/// Read labels: an array of strings, or a single comma-separated string.
+fn labels_value(value: &Value) -> Result<Vec<String>, &'static str> {
+    match value {
+        Value::Null => Ok(Vec::new()),
+        Value::Array(items) => items
+            .iter()
+            .map(|item| item.as_str().map(str::to_string).ok_or("expected strings"))
+            .collect(),
+        Value::String(s) => Ok(s
+            .split(',')
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .map(str::to_string)
+            .collect()),
+        _ => Err("expected an array of labels"),
+    }
+}
+
+/// Whether a value needed coercion to fit the field's type.
+fn needs_coercion(field: &str, value: &Value) -> bool {
+    match field {
+        // JS numbers arrive as floats, so integral floats are not a coercion
+        "priority" | "estimate" => value.is_string(),
+        "labels" => !matches!(value, Value::Null | Value::Array(_)),
+        _ => !matches!(value, Value::Null | Value::String(_)),
+    }
+}
+
+/// Load one record, appending warnings for every repair.
+fn load_record(index: usize, value: &Value, warnings: &mut Vec<RecordWarning>) -> IssueRecord {
+    let mut warn = |field: &str, message: String| {
+        warnings.push(RecordWarning {
+            index,
+            field: field.to_string(),
+            message,
+        });
+    };
+
+    let mut record = IssueRecord::default();
+    let Some(object) = value.as_object() else {
+        warn("", "record is not an object; loaded as empty".to_string());
+        return record;
+    };
+
+    for (key, value) in object {
+        let field = match FIELD_ALIASES.iter().find(|(old, _)| old == key) {
+            Some(&(_, current)) if object.contains_key(current) => {
+                warn(key, format!("old field name ignored, `{}` is set", current));
+                continue;
+            }
+            Some(&(_, current)) => {
+                warn(key, format!("old field name read as `{}`", current));
+                current
+            }
+            None => key.as_str(),
+        };
+
+        let result = match field {
+            "title" => string_value(value).map(|v| record.title = v),
+            "status" => string_value(value).map(|v| record.status = v),
+            "priority" => priority_value(value).map(|v| record.priority = v),
+            "issue_type" => string_value(value).map(|v| record.issue_type = v),
+            "assignee" => string_value(value).map(|v| record.assignee = v),
+            "labels" => labels_value(value).map(|v| record.labels = v),
+            "created_at" => string_value(value).map(|v| record.created_at = v),
+            "updated_at" => string_value(value).map(|v| record.updated_at = v),
+            "closed_at" => string_value(value).map(|v| record.closed_at = v),
+            "estimate" => estimate_value(value).map(|v| record.estimate = v),
+            // Unknown fields are expected (descriptions, comments, ...) and ignored
+            _ => continue,
+        };
+        match result {
+            Ok(()) if needs_coercion(field, value) => {
+                warn(key, format!("coerced {} to {}", value, field));
+            }
+            Ok(()) => {}
+            Err(expected) => warn(key, format!("{}, got {}; value ignored", expected, value)),
+        }
+    }
+    record
+}
+
+/// Load records leniently, collecting warnings instead of failing.
+///
+/// Always returns one record per input value, so indices stay parallel
+/// to the graph's nodes.
+pub fn load_records(values: &[Value]) -> LoadedRecords {
+    let mut warnings = Vec::new();
+    let records = values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| load_record(i, v, &mut warnings))
+        .collect();
+    LoadedRecords { records, warnings }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_load_clean_records() {
+        let values = vec![json!({
+            "title": "Fix login",
+            "status": "open",
+            "priority": 1,
+            "labels": ["auth"],
+            "description": "unknown fields are fine",
+        })];
+        let loaded = load_records(&values);
+        assert!(loaded.warnings.is_empty());
+        assert_eq!(loaded.records[0].title, "Fix login");
+        assert_eq!(loaded.records[0].priority, Some(1));
+        assert_eq!(loaded.records[0].labels, vec!["auth"]);
+    }
+
+    #[test]
+    fn test_load_old_field_names() {
+        let values = vec![json!({"type": "bug", "owner": "ana", "tags": "ui, db"})];
+        let loaded = load_records(&values);
+        let record = &loaded.records[0];
+        assert_eq!(record.issue_type, "bug");
+        assert_eq!(record.assignee, "ana");
+        assert_eq!(record.labels, vec!["ui", "db"]);
+        // One rename warning per field, plus the string-to-labels coercion
+        assert_eq!(loaded.warnings.len(), 4);
+        assert_eq!(loaded.warnings[0].field, "owner");
+
+        // The current name wins over an old one
+        let values = vec![json!({"type": "bug", "issue_type": "task"})];
+        let loaded = load_records(&values);
+        assert_eq!(loaded.records[0].issue_type, "task");
+        assert_eq!(loaded.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_load_estimates() {
+        let values = vec![
+            json!({"estimate": 3}),
+            json!({"points": "2.5"}),
+            json!({"estimate": -1}),
+        ];
+        let loaded = load_records(&values);
+        assert_eq!(loaded.records[0].estimate, Some(3.0));
+        assert_eq!(loaded.records[1].estimate, Some(2.5));
+        assert_eq!(loaded.records[2].estimate, None);
+        // Rename and coercion for the second record, rejection for the third
+        let fields: Vec<&str> = loaded.warnings.iter().map(|w| w.field.as_str()).collect();
+        assert_eq!(fields, vec!["points", "points", "estimate"]);
+    }
+
+    #[test]
+    fn test_load_bad_values() {
+        let values = vec![
+            json!({"priority": "P2", "title": 42}),
+            json!({"priority": "high", "labels": {"a": 1}, "status": "open"}),
+            json!("not a record"),
+        ];
+        let loaded = load_records(&values);
+        assert_eq!(loaded.records.len(), 3);
+        assert_eq!(loaded.records[0].priority, Some(2));
+        assert_eq!(loaded.records[0].title, "42");
+        assert_eq!(loaded.records[1].priority, None);
+        assert!(loaded.records[1].labels.is_empty());
+        assert_eq!(loaded.records[1].status, "open");
+
+        let per_record: Vec<usize> = loaded.warnings.iter().map(|w| w.index).collect();
+        assert_eq!(per_record, vec![0, 0, 1, 1, 2]);
+        assert!(loaded.warnings[4].field.is_empty());
+    }
+}
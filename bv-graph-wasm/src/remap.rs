@@ -0,0 +1,162 @@
+//! Index remapping across data reloads.
+//!
+//! Node indices are assigned in load order, so a reloaded dataset can
+//! renumber every issue. Remapping by ID lets a view carry its selection,
+//! scroll anchor, and marks over to the new graph, and reports whether the
+//! structure changed (issues or dependencies added or removed). Field edits
+//! such as status, title or label changes are not visible here; compare the
+//! records with `diff::snapshot_diff` for those.
+
+use crate::graph::DiGraph;
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// Mapping from a previous graph's indices to the current graph's indices.
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexRemap {
+    /// New index for each old index (None if the node was removed)
+    pub mapping: Vec<Option<usize>>,
+    /// New indices of nodes that did not exist before
+    pub added: Vec<usize>,
+    /// Old indices of nodes that no longer exist
+    pub removed: Vec<usize>,
+    /// True if any node or edge was added or removed; field edits on
+    /// surviving issues do not count
+    pub structure_changed: bool,
+}
+
+/// Match nodes of `previous` to nodes of `current` by ID.
+pub fn remap_indices(previous: &DiGraph, current: &DiGraph) -> IndexRemap {
+    let mapping: Vec<Option<usize>> = (0..previous.len())
+        .map(|v| previous.node_id(v).and_then(|id| current.node_idx(&id)))
+        .collect();
+
+    let removed: Vec<usize> = (0..previous.len())
+        .filter(|&v| mapping[v].is_none())
+        .collect();
+
+    let mut seen = vec![false; current.len()];
+    for &w in mapping.iter().flatten() {
+        seen[w] = true;
+    }
+    let added: Vec<usize> = (0..current.len()).filter(|&w| !seen[w]).collect();
+
+    let structure_changed =
+        !added.is_empty() || !removed.is_empty() || edges_differ(previous, current, &mapping);
+
+    IndexRemap {
+        mapping,
+        added,
+        removed,
+        structure_changed,
+    }
+}
+
+/// Compare edge sets through the mapping (only meaningful when no nodes changed).
+fn edges_differ(previous: &DiGraph, current: &DiGraph, mapping: &[Option<usize>]) -> bool {
+    if previous.edge_count() != current.edge_count() {
+        return true;
+    }
+    let current_edges: HashSet<(usize, usize)> = current.edges().collect();
+    previous
+        .edges()
+        .any(|(u, v)| match (mapping[u], mapping[v]) {
+            (Some(nu), Some(nv)) => !current_edges.contains(&(nu, nv)),
+            _ => true,
+        })
+}
+
+/// Carry a selected index over to the current graph.
+///
+/// If the selected node was removed, falls back to the nearest surviving
+/// node in the previous index order (next first, then previous), so the
+/// cursor stays close to where it was. Returns None if nothing survived.
+pub fn remap_selection(remap: &IndexRemap, selected: usize) -> Option<usize> {
+    let mapping = &remap.mapping;
+    if selected >= mapping.len() {
+        return None;
+    }
+    mapping[selected..]
+        .iter()
+        .flatten()
+        .next()
+        .or_else(|| mapping[..selected].iter().rev().flatten().next())
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(ids: &[&str], edges: &[(usize, usize)]) -> DiGraph {
+        let mut g = DiGraph::new();
+        for id in ids {
+            g.add_node(id);
+        }
+        for &(u, v) in edges {
+            g.add_edge(u, v);
+        }
+        g
+    }
+
+    #[test]
+    fn test_remap_unchanged() {
+        let old = graph(&["a", "b", "c"], &[(0, 1)]);
+        let new = graph(&["a", "b", "c"], &[(0, 1)]);
+        let remap = remap_indices(&old, &new);
+        assert_eq!(remap.mapping, vec![Some(0), Some(1), Some(2)]);
+        assert!(!remap.structure_changed);
+    }
+
+    #[test]
+    fn test_remap_renumbered() {
+        // Same data loaded in a different order
+        let old = graph(&["a", "b", "c"], &[(0, 1)]);
+        let new = graph(&["c", "a", "b"], &[(1, 2)]);
+        let remap = remap_indices(&old, &new);
+        assert_eq!(remap.mapping, vec![Some(1), Some(2), Some(0)]);
+        assert!(remap.added.is_empty());
+        assert!(remap.removed.is_empty());
+        assert!(!remap.structure_changed);
+    }
+
+    #[test]
+    fn test_remap_added_removed() {
+        let old = graph(&["a", "b", "c"], &[]);
+        let new = graph(&["a", "c", "d"], &[]);
+        let remap = remap_indices(&old, &new);
+        assert_eq!(remap.mapping, vec![Some(0), None, Some(1)]);
+        assert_eq!(remap.added, vec![2]);
+        assert_eq!(remap.removed, vec![1]);
+        assert!(remap.structure_changed);
+    }
+
+    #[test]
+    fn test_remap_edge_change_detected() {
+        let old = graph(&["a", "b", "c"], &[(0, 1)]);
+        let new = graph(&["a", "b", "c"], &[(0, 2)]);
+        assert!(remap_indices(&old, &new).structure_changed);
+    }
+
+    #[test]
+    fn test_remap_selection_falls_back() {
+        let old = graph(&["a", "b", "c", "d"], &[]);
+        let new = graph(&["d", "a"], &[]);
+        let remap = remap_indices(&old, &new);
+        assert_eq!(remap_selection(&remap, 0), Some(1));
+        // b removed: next survivor is d
+        assert_eq!(remap_selection(&remap, 1), Some(0));
+
+        let empty = remap_indices(&old, &DiGraph::new());
+        assert_eq!(remap_selection(&empty, 2), None);
+        assert_eq!(remap_selection(&remap, 99), None);
+    }
+
+    #[test]
+    fn test_remap_selection_previous_fallback() {
+        let old = graph(&["a", "b", "c"], &[]);
+        let new = graph(&["a"], &[]);
+        let remap = remap_indices(&old, &new);
+        assert_eq!(remap_selection(&remap, 2), Some(0));
+    }
+}
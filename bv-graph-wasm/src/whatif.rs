@@ -2,10 +2,15 @@
 //!
 //! What-If analysis answers "If I close issue X, what happens?"
 //! It computes direct unblocks, transitive cascades, and impact metrics.
+//! The sandbox extends this to hypothetical dependency edits as well.
 
+use crate::algorithms::articulation::articulation_points;
+use crate::algorithms::critical_path::{critical_path_members, open_critical_path_length};
+use crate::algorithms::cycles::{is_cycle_component, tarjan_scc};
+use crate::algorithms::subgraph::extract_subgraph;
 use crate::graph::DiGraph;
-use crate::reachability::{actionable_nodes, is_actionable};
-use serde::Serialize;
+use crate::reachability::{actionable_nodes, is_actionable, open_blocker_count};
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 /// Result of a what-if simulation for closing a single node.
@@ -290,6 +295,163 @@ pub fn what_if_close_batch(
     }
 }
 
+/// Preview shown before closing or deleting an issue.
+#[derive(Debug, Clone, Serialize)]
+pub struct CloseImpact {
+    /// Node being closed or deleted
+    pub node: usize,
+    /// Cascade impact of closing the node
+    pub what_if: WhatIfResult,
+    /// Longest chain of open work now
+    pub critical_path_before: f64,
+    /// Longest chain of open work once the node is closed
+    pub critical_path_after: f64,
+    /// True if closing the node shortens the critical path
+    pub shortens_critical_path: bool,
+    /// True if deleting the node would split its connected component
+    pub disconnects: bool,
+}
+
+/// Preview the downstream impact of closing or deleting a node.
+///
+/// Closing and deleting unblock the same dependents and shorten the same
+/// chains; `disconnects` only matters for deletion, where the node's edges
+/// go away with it (articulation point check on the undirected view).
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `node` - Node to close or delete
+/// * `closed_set` - Boolean array indicating which nodes are already closed
+pub fn close_impact(graph: &DiGraph, node: usize, closed_set: &[bool]) -> CloseImpact {
+    let n = graph.len();
+    let before = open_critical_path_length(graph, closed_set);
+    if node >= n {
+        return CloseImpact {
+            node,
+            what_if: WhatIfResult::empty(),
+            critical_path_before: before,
+            critical_path_after: before,
+            shortens_critical_path: false,
+            disconnects: false,
+        };
+    }
+
+    let mut closed = closed_set.to_vec();
+    closed.resize(n, false);
+    closed[node] = true;
+    let after = open_critical_path_length(graph, &closed);
+
+    CloseImpact {
+        node,
+        what_if: what_if_close(graph, node, closed_set),
+        critical_path_before: before,
+        critical_path_after: after,
+        shortens_critical_path: after < before,
+        disconnects: articulation_points(graph).contains(&node),
+    }
+}
+
+/// Hypothetical changes explored in the sandbox.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct Scenario {
+    /// Nodes to treat as done
+    pub close: Vec<usize>,
+    /// Dependencies to add as (blocker, blocked)
+    pub add_edges: Vec<(usize, usize)>,
+    /// Dependencies to remove as (blocker, blocked)
+    pub remove_edges: Vec<(usize, usize)>,
+}
+
+/// Recomputed planning state under a scenario, next to the current one.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScenarioResult {
+    /// Longest chain of open work now
+    pub critical_path_before: f64,
+    /// Longest chain of open work under the scenario
+    pub critical_path_after: f64,
+    /// Open nodes on a longest remaining chain under the scenario
+    pub critical_path: Vec<usize>,
+    /// Actionable nodes under the scenario
+    pub ready: Vec<usize>,
+    /// Nodes that become actionable
+    pub newly_ready: Vec<usize>,
+    /// Open nodes that were actionable and get blocked by an added dependency
+    pub newly_blocked: Vec<usize>,
+    /// Open nodes with at least one open blocker now
+    pub blocked_before: usize,
+    /// Open nodes with at least one open blocker under the scenario
+    pub blocked_after: usize,
+    /// Whether the scenario's dependencies form a cycle (self-loops included)
+    pub has_cycles: bool,
+}
+
+/// Count open nodes that still wait on an open blocker.
+fn blocked_count(graph: &DiGraph, closed_set: &[bool]) -> usize {
+    (0..graph.len())
+        .filter(|&v| !closed_set[v] && open_blocker_count(graph, v, closed_set) > 0)
+        .count()
+}
+
+/// Evaluate a sandbox scenario without touching the graph.
+///
+/// The scenario is applied to a copy: removals first, then additions, so
+/// re-pointing a dependency is one remove plus one add. Out-of-range
+/// indices are ignored.
+///
+/// # Arguments
+/// * `graph` - The dependency graph
+/// * `closed_set` - Boolean array indicating which nodes are already closed
+/// * `scenario` - Hypothetical closes and dependency edits
+pub fn evaluate_scenario(
+    graph: &DiGraph,
+    closed_set: &[bool],
+    scenario: &Scenario,
+) -> ScenarioResult {
+    let n = graph.len();
+    let mut closed = closed_set.to_vec();
+    closed.resize(n, false);
+    let mut sandbox = extract_subgraph(graph, &(0..n).collect::<Vec<_>>());
+    for &(from, to) in &scenario.remove_edges {
+        sandbox.remove_edge(from, to);
+    }
+    for &(from, to) in &scenario.add_edges {
+        sandbox.add_edge(from, to);
+    }
+    let mut sandbox_closed = closed.clone();
+    for &v in scenario.close.iter().filter(|&&v| v < n) {
+        sandbox_closed[v] = true;
+    }
+
+    let ready_before = actionable_nodes(graph, &closed);
+    let ready = actionable_nodes(&sandbox, &sandbox_closed);
+    let newly_ready = ready
+        .iter()
+        .copied()
+        .filter(|v| ready_before.binary_search(v).is_err())
+        .collect();
+    let newly_blocked = ready_before
+        .iter()
+        .copied()
+        .filter(|&v| !sandbox_closed[v] && ready.binary_search(&v).is_err())
+        .collect();
+
+    ScenarioResult {
+        critical_path_before: open_critical_path_length(graph, &closed),
+        critical_path_after: open_critical_path_length(&sandbox, &sandbox_closed),
+        critical_path: critical_path_members(&sandbox, &sandbox_closed),
+        ready,
+        newly_ready,
+        newly_blocked,
+        blocked_before: blocked_count(graph, &closed),
+        blocked_after: blocked_count(&sandbox, &sandbox_closed),
+        has_cycles: tarjan_scc(&sandbox)
+            .components
+            .iter()
+            .any(|c| is_cycle_component(&sandbox, c)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -621,4 +783,115 @@ mod tests {
         assert_eq!(result.cascade_ids[1], c);
         assert_eq!(result.cascade_ids[2], d);
     }
+
+    #[test]
+    fn test_close_impact_chain() {
+        // a -> b -> c
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let impact = close_impact(&graph, a, &[]);
+        assert_eq!(impact.what_if.unblocked_ids, vec![b]);
+        assert_eq!(impact.critical_path_before, 3.0);
+        assert_eq!(impact.critical_path_after, 2.0);
+        assert!(impact.shortens_critical_path);
+        assert!(!impact.disconnects);
+
+        // Deleting the middle of the chain splits it
+        assert!(close_impact(&graph, b, &[]).disconnects);
+    }
+
+    #[test]
+    fn test_close_impact_off_critical_path() {
+        // a -> b -> c, d isolated
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+
+        let impact = close_impact(&graph, d, &[]);
+        assert!(!impact.shortens_critical_path);
+        assert_eq!(impact.what_if.direct_unblocks, 0);
+
+        let invalid = close_impact(&graph, 99, &[]);
+        assert_eq!(invalid.critical_path_after, invalid.critical_path_before);
+    }
+
+    #[test]
+    fn test_evaluate_scenario() {
+        // a -> b -> c, d -> c, e isolated
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        let e = graph.add_node("e");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(d, c);
+
+        // Close a, move c's dependency on d onto e
+        let scenario = Scenario {
+            close: vec![a],
+            add_edges: vec![(e, d)],
+            remove_edges: vec![(d, c)],
+        };
+        let result = evaluate_scenario(&graph, &[], &scenario);
+        assert_eq!(result.critical_path_before, 3.0);
+        assert_eq!(result.critical_path_after, 2.0);
+        assert_eq!(result.ready, vec![b, e]);
+        assert_eq!(result.newly_ready, vec![b]);
+        assert_eq!(result.newly_blocked, vec![d]);
+        assert_eq!(result.blocked_before, 2);
+        assert_eq!(result.blocked_after, 2);
+        assert!(!result.has_cycles);
+
+        // The real graph is untouched
+        assert!(graph.successors_slice(d).contains(&c));
+        assert_eq!(graph.edge_count(), 3);
+    }
+
+    #[test]
+    fn test_evaluate_scenario_cycle() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+
+        let scenario = Scenario {
+            add_edges: vec![(b, a), (7, 9)],
+            ..Scenario::default()
+        };
+        let result = evaluate_scenario(&graph, &[], &scenario);
+        assert!(result.has_cycles);
+        assert!(result.ready.is_empty());
+        assert_eq!(result.newly_blocked, vec![a]);
+        assert_eq!(
+            evaluate_scenario(&graph, &[], &Scenario::default()).ready,
+            vec![a]
+        );
+    }
+
+    #[test]
+    fn test_evaluate_scenario_self_loop() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+
+        let scenario = Scenario {
+            add_edges: vec![(a, a)],
+            ..Scenario::default()
+        };
+        let result = evaluate_scenario(&graph, &[], &scenario);
+        assert!(result.has_cycles);
+        assert!(result.ready.is_empty());
+    }
 }